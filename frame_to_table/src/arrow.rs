@@ -0,0 +1,119 @@
+use arrow_array::{
+    cast::AsArray,
+    types::{Float32Type, Float64Type},
+    Array, RecordBatch,
+};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::DataType;
+
+use tabled::{builder::Builder, Table};
+
+use crate::{build_builder, build_table, FrameColumn, FrameFormat, FrameTable};
+
+impl FrameFormat {
+    /// Converts an Arrow [`RecordBatch`] into a [`Table`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+    /// use frame_to_table::FrameFormat;
+    /// use tabled::settings::Style;
+    ///
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("name", Arc::new(StringArray::from(vec!["pi", "e"])) as ArrayRef),
+    ///     ("value", Arc::new(Float64Array::from(vec![Some(3.14159), None])) as ArrayRef),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let mut table = FrameFormat::new().precision(2).null("-").record_batch(&batch);
+    /// table.with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     " name | value \n\
+    ///     ------+-------\n \
+    ///      pi   |  3.14 \n \
+    ///      e    |     - ",
+    /// );
+    /// ```
+    pub fn record_batch(&self, batch: &RecordBatch) -> Table {
+        build_table(arrow_columns(batch, self))
+    }
+
+    /// Converts an Arrow [`RecordBatch`] into a [`Builder`].
+    ///
+    /// Unlike [`FrameFormat::record_batch`] it has no alignment set.
+    pub fn record_batch_builder(&self, batch: &RecordBatch) -> Builder {
+        build_builder(arrow_columns(batch, self))
+    }
+}
+
+/// Converts an Arrow [`RecordBatch`] into a [`Table`] using a default [`FrameFormat`].
+pub fn from_record_batch(batch: &RecordBatch) -> Table {
+    FrameFormat::default().record_batch(batch)
+}
+
+impl From<FrameTable<'_, RecordBatch>> for Table {
+    fn from(table: FrameTable<'_, RecordBatch>) -> Self {
+        from_record_batch(table.frame)
+    }
+}
+
+impl From<FrameTable<'_, RecordBatch>> for Builder {
+    fn from(table: FrameTable<'_, RecordBatch>) -> Self {
+        FrameFormat::default().record_batch_builder(table.frame)
+    }
+}
+
+fn arrow_columns(batch: &RecordBatch, format: &FrameFormat) -> Vec<FrameColumn> {
+    let schema = batch.schema();
+
+    schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| FrameColumn {
+            name: field.name().clone(),
+            values: format_array(array.as_ref(), format),
+            is_numeric: field.data_type().is_numeric(),
+        })
+        .collect()
+}
+
+fn format_array(array: &dyn Array, format: &FrameFormat) -> Vec<String> {
+    let format_value = |i: usize, value: String| {
+        if array.is_null(i) {
+            format.null.clone()
+        } else {
+            value
+        }
+    };
+
+    match array.data_type() {
+        DataType::Float32 => {
+            let array = array.as_primitive::<Float32Type>();
+            (0..array.len())
+                .map(|i| format_value(i, format.format_float(array.value(i))))
+                .collect()
+        }
+        DataType::Float64 => {
+            let array = array.as_primitive::<Float64Type>();
+            (0..array.len())
+                .map(|i| format_value(i, format.format_float(array.value(i))))
+                .collect()
+        }
+        _ => {
+            let options = FormatOptions::new()
+                .with_null(&format.null)
+                .with_display_error(true);
+
+            match ArrayFormatter::try_new(array, &options) {
+                Ok(formatter) => (0..array.len())
+                    .map(|i| formatter.value(i).to_string())
+                    .collect(),
+                Err(_) => vec![format.null.clone(); array.len()],
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+#![warn(missing_docs, rust_2018_idioms, unreachable_pub)]
+#![deny(unused_must_use)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(any(feature = "arrow", feature = "polars")), allow(dead_code))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! A library which converts data frames into a [`Table`].
+//!
+//! Supported data frames are behind features,
+//! `arrow` enables an Arrow `RecordBatch` and `polars` a Polars `DataFrame`.
+//!
+//! Column names are used as a header,
+//! values are formatted according to a column type
+//! and values of numeric columns are aligned to the right.
+//!
+//! It's a separate crate as the data frame libraries require a newer Rust than [`tabled`].
+
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "polars")]
+mod polars;
+
+use std::fmt::Display;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Columns, Object, Rows},
+        Alignment, Modify,
+    },
+    Table,
+};
+
+#[cfg(feature = "arrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub use arrow::from_record_batch;
+#[cfg(feature = "polars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+pub use polars::from_data_frame;
+
+/// A format of a data frame conversion.
+///
+/// `from_record_batch` and `from_data_frame` use a default format,
+/// which prints floats as they are and nulls as empty cells.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FrameFormat {
+    precision: Option<usize>,
+    null: String,
+}
+
+impl FrameFormat {
+    /// Creates a default [`FrameFormat`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a number of digits printed after a decimal point of floats.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets a text which is printed in place of nulls.
+    pub fn null<S>(mut self, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.null = text.into();
+        self
+    }
+
+    fn format_float<T>(&self, value: T) -> String
+    where
+        T: Display,
+    {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// A reference to a data frame which converts into a [`Table`] or a [`Builder`] by [`From`].
+///
+/// `From<&RecordBatch>` and `From<&DataFrame>` can't be implemented for [`Table`] and [`Builder`]
+/// in this crate as all of the types are foreign, so a data frame is wrapped into [`FrameTable`].
+///
+/// A default [`FrameFormat`] is used.
+///
+/// ```
+/// # #[cfg(feature = "polars")]
+/// # {
+/// use polars_core::df;
+/// use frame_to_table::FrameTable;
+/// use tabled::Table;
+///
+/// let frame = df!("name" => ["pi", "e"]).unwrap();
+///
+/// let table = Table::from(FrameTable::new(&frame));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+\n\
+///      | name |\n\
+///      +------+\n\
+///      | pi   |\n\
+///      +------+\n\
+///      | e    |\n\
+///      +------+",
+/// );
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FrameTable<'a, F> {
+    frame: &'a F,
+}
+
+impl<'a, F> FrameTable<'a, F> {
+    /// Creates a new [`FrameTable`] structure.
+    pub fn new(frame: &'a F) -> Self {
+        Self { frame }
+    }
+}
+
+impl<'a, F> From<&'a F> for FrameTable<'a, F> {
+    fn from(frame: &'a F) -> Self {
+        Self::new(frame)
+    }
+}
+
+/// A formatted column of a data frame.
+struct FrameColumn {
+    name: String,
+    values: Vec<String>,
+    is_numeric: bool,
+}
+
+fn build_builder(columns: Vec<FrameColumn>) -> Builder {
+    let count_rows = columns.iter().map(|c| c.values.len()).max().unwrap_or(0);
+
+    let mut rows = vec![Vec::with_capacity(columns.len()); count_rows + 1];
+    for column in columns {
+        rows[0].push(column.name);
+
+        let mut values = column.values.into_iter();
+        for row in &mut rows[1..] {
+            row.push(values.next().unwrap_or_default());
+        }
+    }
+
+    if rows[0].is_empty() {
+        return Builder::default();
+    }
+
+    Builder::from(rows)
+}
+
+fn build_table(columns: Vec<FrameColumn>) -> Table {
+    let numeric = columns
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.is_numeric)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let mut table = build_builder(columns).build();
+    for col in numeric {
+        // a header is a column name, so it's left as it is.
+        let values = Columns::single(col).intersect(Rows::new(1..));
+        let _ = table.with(Modify::new(values).with(Alignment::right()));
+    }
+
+    table
+}
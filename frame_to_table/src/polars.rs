@@ -0,0 +1,92 @@
+use polars_core::{frame::DataFrame, prelude::AnyValue};
+
+use tabled::{builder::Builder, Table};
+
+use crate::{build_builder, build_table, FrameColumn, FrameFormat, FrameTable};
+
+impl FrameFormat {
+    /// Converts a Polars [`DataFrame`] into a [`Table`].
+    ///
+    /// ```
+    /// use polars_core::{df, prelude::*};
+    /// use frame_to_table::FrameFormat;
+    /// use tabled::settings::Style;
+    ///
+    /// let frame = df!(
+    ///     "name" => ["pi", "e"],
+    ///     "value" => [Some(3.14159), None],
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut table = FrameFormat::new().precision(2).null("-").data_frame(&frame);
+    /// table.with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     " name | value \n\
+    ///     ------+-------\n \
+    ///      pi   |  3.14 \n \
+    ///      e    |     - ",
+    /// );
+    /// ```
+    pub fn data_frame(&self, frame: &DataFrame) -> Table {
+        build_table(polars_columns(frame, self))
+    }
+
+    /// Converts a Polars [`DataFrame`] into a [`Builder`].
+    ///
+    /// Unlike [`FrameFormat::data_frame`] it has no alignment set.
+    pub fn data_frame_builder(&self, frame: &DataFrame) -> Builder {
+        build_builder(polars_columns(frame, self))
+    }
+}
+
+/// Converts a Polars [`DataFrame`] into a [`Table`] using a default [`FrameFormat`].
+pub fn from_data_frame(frame: &DataFrame) -> Table {
+    FrameFormat::default().data_frame(frame)
+}
+
+impl From<FrameTable<'_, DataFrame>> for Table {
+    fn from(table: FrameTable<'_, DataFrame>) -> Self {
+        from_data_frame(table.frame)
+    }
+}
+
+impl From<FrameTable<'_, DataFrame>> for Builder {
+    fn from(table: FrameTable<'_, DataFrame>) -> Self {
+        FrameFormat::default().data_frame_builder(table.frame)
+    }
+}
+
+fn polars_columns(frame: &DataFrame, format: &FrameFormat) -> Vec<FrameColumn> {
+    frame
+        .get_columns()
+        .iter()
+        .map(|column| {
+            // iteration requires a single chunk.
+            let series = column.as_materialized_series().rechunk();
+            let values = series
+                .iter()
+                .map(|value| format_value(value, format))
+                .collect();
+
+            FrameColumn {
+                name: column.name().to_string(),
+                values,
+                is_numeric: column.dtype().is_numeric(),
+            }
+        })
+        .collect()
+}
+
+fn format_value(value: AnyValue<'_>, format: &FrameFormat) -> String {
+    match value {
+        AnyValue::Null => format.null.clone(),
+        AnyValue::Float32(value) => format.format_float(value),
+        AnyValue::Float64(value) => format.format_float(value),
+        value => match value.get_str() {
+            Some(text) => text.to_owned(),
+            None => value.to_string(),
+        },
+    }
+}
@@ -0,0 +1,274 @@
+#![cfg(any(feature = "arrow", feature = "polars"))]
+
+use frame_to_table::{FrameFormat, FrameTable};
+use tabled::{builder::Builder, settings::Style, Table};
+
+#[cfg(feature = "arrow")]
+mod arrow {
+    use std::sync::Arc;
+
+    use arrow_array::{
+        ArrayRef, BooleanArray, Float32Array, Float64Array, Int64Array, RecordBatch, StringArray,
+        UInt8Array,
+    };
+    use frame_to_table::from_record_batch;
+
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        RecordBatch::try_from_iter([
+            (
+                "name",
+                Arc::new(StringArray::from(vec![Some("Debian"), None, Some("Arch")])) as ArrayRef,
+            ),
+            (
+                "version",
+                Arc::new(Int64Array::from(vec![Some(11), Some(22), None])) as ArrayRef,
+            ),
+            (
+                "score",
+                Arc::new(Float64Array::from(vec![Some(1.5), Some(10.125), None])) as ArrayRef,
+            ),
+            (
+                "active",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn arrow_table() {
+        let table = from_record_batch(&batch());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "+--------+---------+--------+--------+\n",
+                "| name   | version | score  | active |\n",
+                "+--------+---------+--------+--------+\n",
+                "| Debian |      11 |    1.5 | true   |\n",
+                "+--------+---------+--------+--------+\n",
+                "|        |      22 | 10.125 | false  |\n",
+                "+--------+---------+--------+--------+\n",
+                "| Arch   |         |        | true   |\n",
+                "+--------+---------+--------+--------+",
+            )
+        );
+    }
+
+    #[test]
+    fn arrow_builder() {
+        let mut table = FrameFormat::new().record_batch_builder(&batch()).build();
+        table.with(Style::psql());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                " name   | version | score  | active \n",
+                "--------+---------+--------+--------\n",
+                " Debian | 11      | 1.5    | true   \n",
+                "        | 22      | 10.125 | false  \n",
+                " Arch   |         |        | true   ",
+            )
+        );
+    }
+
+    #[test]
+    fn arrow_from() {
+        let table = Table::from(FrameTable::new(&batch()));
+        assert_eq!(
+            table.to_string(),
+            FrameFormat::new().record_batch(&batch()).to_string()
+        );
+
+        let builder = Builder::from(FrameTable::from(&batch()));
+        assert_eq!(
+            builder.build().to_string(),
+            FrameFormat::new()
+                .record_batch_builder(&batch())
+                .build()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn arrow_format() {
+        let mut table = FrameFormat::new()
+            .precision(2)
+            .null("NULL")
+            .record_batch(&batch());
+        table.with(Style::psql());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                " name   | version | score | active \n",
+                "--------+---------+-------+--------\n",
+                " Debian |      11 |  1.50 | true   \n",
+                " NULL   |      22 | 10.12 | false  \n",
+                " Arch   |    NULL |  NULL | true   ",
+            )
+        );
+    }
+
+    #[test]
+    fn arrow_float32_and_unsigned() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Float32Array::from(vec![0.25, 2.0])) as ArrayRef,
+            ),
+            ("b", Arc::new(UInt8Array::from(vec![1, 255])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut table = FrameFormat::new().precision(1).record_batch(&batch);
+        table.with(Style::psql());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                " a   | b   \n",
+                "-----+-----\n",
+                " 0.2 |   1 \n",
+                " 2.0 | 255 ",
+            )
+        );
+    }
+
+    #[test]
+    fn arrow_no_rows() {
+        let batch = RecordBatch::try_from_iter([(
+            "name",
+            Arc::new(StringArray::from(Vec::<&str>::new())) as ArrayRef,
+        )])
+        .unwrap();
+
+        let table = from_record_batch(&batch);
+
+        assert_eq!(
+            table.to_string(),
+            concat!("+------+\n", "| name |\n", "+------+")
+        );
+    }
+
+    #[test]
+    fn arrow_empty() {
+        let batch = RecordBatch::new_empty(Arc::new(arrow_schema::Schema::empty()));
+
+        assert_eq!(from_record_batch(&batch).to_string(), "");
+    }
+}
+
+#[cfg(feature = "polars")]
+mod polars {
+    use frame_to_table::from_data_frame;
+    use polars_core::{df, frame::DataFrame};
+
+    use super::*;
+
+    fn frame() -> DataFrame {
+        df!(
+            "name" => [Some("Debian"), None, Some("Arch")],
+            "version" => [Some(11i64), Some(22), None],
+            "score" => [Some(1.5f64), Some(10.125), None],
+            "active" => [true, false, true],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn polars_table() {
+        let table = from_data_frame(&frame());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "+--------+---------+--------+--------+\n",
+                "| name   | version | score  | active |\n",
+                "+--------+---------+--------+--------+\n",
+                "| Debian |      11 |    1.5 | true   |\n",
+                "+--------+---------+--------+--------+\n",
+                "|        |      22 | 10.125 | false  |\n",
+                "+--------+---------+--------+--------+\n",
+                "| Arch   |         |        | true   |\n",
+                "+--------+---------+--------+--------+",
+            )
+        );
+    }
+
+    #[test]
+    fn polars_builder() {
+        let mut table = FrameFormat::new().data_frame_builder(&frame()).build();
+        table.with(Style::psql());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                " name   | version | score  | active \n",
+                "--------+---------+--------+--------\n",
+                " Debian | 11      | 1.5    | true   \n",
+                "        | 22      | 10.125 | false  \n",
+                " Arch   |         |        | true   ",
+            )
+        );
+    }
+
+    #[test]
+    fn polars_from() {
+        let table = Table::from(FrameTable::new(&frame()));
+        assert_eq!(
+            table.to_string(),
+            FrameFormat::new().data_frame(&frame()).to_string()
+        );
+
+        let builder = Builder::from(FrameTable::from(&frame()));
+        assert_eq!(
+            builder.build().to_string(),
+            FrameFormat::new()
+                .data_frame_builder(&frame())
+                .build()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn polars_format() {
+        let mut table = FrameFormat::new()
+            .precision(2)
+            .null("NULL")
+            .data_frame(&frame());
+        table.with(Style::psql());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                " name   | version | score | active \n",
+                "--------+---------+-------+--------\n",
+                " Debian |      11 |  1.50 | true   \n",
+                " NULL   |      22 | 10.12 | false  \n",
+                " Arch   |    NULL |  NULL | true   ",
+            )
+        );
+    }
+
+    #[test]
+    fn polars_chunked() {
+        let mut frame = df!("n" => [1i64, 2]).unwrap();
+        frame.vstack_mut(&df!("n" => [3i64]).unwrap()).unwrap();
+
+        let mut table = from_data_frame(&frame);
+        table.with(Style::psql());
+
+        assert_eq!(
+            table.to_string(),
+            concat!(" n \n", "---\n", " 1 \n", " 2 \n", " 3 ")
+        );
+    }
+
+    #[test]
+    fn polars_empty() {
+        assert_eq!(from_data_frame(&DataFrame::empty()).to_string(), "");
+    }
+}
@@ -1,6 +1,6 @@
 use json_to_table::json_to_table;
 use serde_json::json;
-use tabled::settings::Style;
+use tabled::{builder::Builder, settings::Style};
 
 #[test]
 fn general_json_1_test() {
@@ -1051,3 +1051,32 @@ fn test_multiline_key_height_less_then_value() {
         )
     );
 }
+
+#[test]
+fn table_to_json_inverse_test() {
+    let value = json!([
+        { "name": "Debian", "version": "11" },
+        { "name": "Arch", "version": "2023" },
+    ]);
+
+    let mut builder = Builder::default().set_header(["name", "version"]);
+    builder.push_record(["Debian", "11"]);
+    builder.push_record(["Arch", "2023"]);
+    let table = builder.build();
+
+    assert_eq!(table.to_json(), value);
+    assert_eq!(
+        json_to_table(&table.to_json()).collapse().to_string(),
+        concat!(
+            "+---------+--------+\n",
+            "| name    | Debian |\n",
+            "+---------+--------+\n",
+            "| version | 11     |\n",
+            "+---------+--------+\n",
+            "| name    | Arch   |\n",
+            "+---------+--------+\n",
+            "| version | 2023   |\n",
+            "+---------+--------+",
+        ),
+    );
+}
@@ -0,0 +1,339 @@
+use std::fmt::{self, Write};
+
+/// A palette of 16 basic colors used to downgrade a color.
+///
+/// It's a default palette of xterm.
+#[rustfmt::skip]
+pub(crate) const BASIC_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0), (128, 0, 0), (0, 128, 0), (128, 128, 0),
+    (0, 0, 128), (128, 0, 128), (0, 128, 128), (192, 192, 192),
+    (128, 128, 128), (255, 0, 0), (0, 255, 0), (255, 255, 0),
+    (0, 0, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255),
+];
+
+/// Levels of a 6x6x6 color cube of a 256 color palette.
+pub(crate) const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// A number of colors a terminal is capable to print.
+///
+/// Colors which are not supported are downgraded to the closest supported ones at a rendering time.
+/// It's up to a caller to decide on a value,
+/// e.g. by checking `NO_COLOR`, `CLICOLOR` or `COLORTERM` environment variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// No colors are printed.
+    ///
+    /// Text attributes like bold or underline are kept.
+    None,
+    /// 16 basic colors.
+    Ansi16,
+    /// 256 colors of a palette.
+    Ansi256,
+    /// 24 bit colors (RGB).
+    TrueColor,
+}
+
+/// A writer which downgrades colors of SGR sequences (`ESC[...m`) written to it.
+#[derive(Debug)]
+pub(crate) struct ColorDepthWriter<W> {
+    f: W,
+    depth: ColorDepth,
+    // an escape sequence which was not completed in a last write.
+    pending: String,
+}
+
+impl<W: Write> ColorDepthWriter<W> {
+    pub(crate) fn new(f: W, depth: ColorDepth) -> Self {
+        Self {
+            f,
+            depth,
+            pending: String::new(),
+        }
+    }
+
+    /// Writes an incomplete escape sequence left after the last write, if any.
+    pub(crate) fn finish(mut self) -> fmt::Result {
+        if !self.pending.is_empty() {
+            self.f.write_str(&self.pending)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for ColorDepthWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.pending.is_empty() && !s.contains('\u{1b}') {
+            return self.f.write_str(s);
+        }
+
+        let mut text = std::mem::take(&mut self.pending);
+        text.push_str(s);
+
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find('\u{1b}') {
+            self.f.write_str(&rest[..start])?;
+            rest = &rest[start..];
+
+            let seq = &rest[1..];
+            if seq.is_empty() || seq == "[" {
+                break;
+            }
+
+            let params = match seq.strip_prefix('[') {
+                Some(params) => params,
+                None => {
+                    // not a CSI sequence, so we leave it as it is.
+                    self.f.write_char('\u{1b}')?;
+                    rest = seq;
+                    continue;
+                }
+            };
+
+            let end = match params.find(|c: char| ('@'..='~').contains(&c)) {
+                Some(end) => end,
+                None => break,
+            };
+
+            if params[end..].starts_with('m') {
+                let params = &params[..end];
+                let downgraded = downgrade_sgr(params, self.depth);
+                // an empty SGR sequence is a reset so we don't print it in case everything was stripped.
+                if !downgraded.is_empty() || params.is_empty() {
+                    write!(self.f, "\u{1b}[{}m", downgraded)?;
+                }
+            } else {
+                self.f.write_str(&rest[..end + 3])?;
+            }
+
+            rest = &params[end + 1..];
+        }
+
+        if rest.starts_with('\u{1b}') {
+            self.pending = rest.to_owned();
+        } else {
+            self.f.write_str(rest)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Changes SGR parameters (e.g. `1;38;2;255;0;0`) so they use only colors supported by a given depth.
+fn downgrade_sgr(params: &str, depth: ColorDepth) -> String {
+    let mut out: Vec<String> = Vec::new();
+
+    let mut iter = params.split(';');
+    while let Some(p) = iter.next() {
+        let code = p.parse::<u16>().ok();
+        match code {
+            Some(code @ (38 | 48 | 58)) => {
+                let color = match iter.next() {
+                    Some("5") => iter
+                        .next()
+                        .and_then(|n| n.parse::<u8>().ok())
+                        .map(ExtendedColor::Palette),
+                    Some("2") => {
+                        let mut next = || iter.next().and_then(|n| n.parse::<u8>().ok());
+                        match (next(), next(), next()) {
+                            (Some(r), Some(g), Some(b)) => Some(ExtendedColor::TrueColor(r, g, b)),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+
+                if let Some(color) = color {
+                    if let Some(color) = downgrade_color(code, color, depth) {
+                        out.push(color);
+                    }
+                }
+            }
+            Some(30..=37 | 39 | 40..=47 | 49 | 59 | 90..=97 | 100..=107) => {
+                if depth != ColorDepth::None {
+                    out.push(p.to_owned());
+                }
+            }
+            _ => out.push(p.to_owned()),
+        }
+    }
+
+    out.join(";")
+}
+
+/// An extended color taken from SGR parameters.
+#[derive(Debug, Clone, Copy)]
+enum ExtendedColor {
+    Palette(u8),
+    TrueColor(u8, u8, u8),
+}
+
+fn downgrade_color(code: u16, color: ExtendedColor, depth: ColorDepth) -> Option<String> {
+    match (depth, color) {
+        (ColorDepth::None, _) => None,
+        (ColorDepth::TrueColor, ExtendedColor::TrueColor(r, g, b)) => {
+            Some(format!("{};2;{};{};{}", code, r, g, b))
+        }
+        (ColorDepth::TrueColor, ExtendedColor::Palette(n))
+        | (ColorDepth::Ansi256, ExtendedColor::Palette(n)) => Some(format!("{};5;{}", code, n)),
+        (ColorDepth::Ansi256, ExtendedColor::TrueColor(r, g, b)) => {
+            Some(format!("{};5;{}", code, rgb_to_ansi256(r, g, b)))
+        }
+        (ColorDepth::Ansi16, color) => {
+            let n = match color {
+                ExtendedColor::Palette(n) if n < 16 => n,
+                ExtendedColor::Palette(n) => {
+                    let (r, g, b) = ansi256_to_rgb(n);
+                    rgb_to_ansi16(r, g, b)
+                }
+                ExtendedColor::TrueColor(r, g, b) => rgb_to_ansi16(r, g, b),
+            };
+
+            // there's no basic underline color so it's dropped.
+            let base = match code {
+                38 => 30,
+                48 => 40,
+                _ => return None,
+            };
+
+            let code = if n < 8 {
+                base + u16::from(n)
+            } else {
+                base + 60 + u16::from(n - 8)
+            };
+
+            Some(code.to_string())
+        }
+    }
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (v - 35) / 40,
+    };
+
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray_index = if average > 238 {
+        23
+    } else {
+        (average.saturating_sub(3) / 10) as u8
+    };
+    let gray_level = 8 + gray_index * 10;
+    let gray = (gray_level, gray_level, gray_level);
+
+    if distance((r, g, b), gray) < distance((r, g, b), cube) {
+        232 + gray_index
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+fn ansi256_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => BASIC_COLORS[n as usize],
+        16..=231 => {
+            let n = n - 16;
+            let level = |i: u8| CUBE_LEVELS[i as usize];
+            (level(n / 36), level(n / 6 % 6), level(n % 6))
+        }
+        _ => {
+            let level = 8 + (n - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    BASIC_COLORS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, &color)| distance((r, g, b), color))
+        .map(|(i, _)| i as u8)
+        .unwrap_or(0)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let diff = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+    diff(a.0, b.0) + diff(a.1, b.1) + diff(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn downgrade(text: &str, depth: ColorDepth) -> String {
+        let mut buf = String::new();
+        let mut writer = ColorDepthWriter::new(&mut buf, depth);
+        writer.write_str(text).unwrap();
+        writer.finish().unwrap();
+        buf
+    }
+
+    #[test]
+    fn downgrade_sgr_test() {
+        use ColorDepth::*;
+
+        assert_eq!(downgrade_sgr("38;2;255;0;0", TrueColor), "38;2;255;0;0");
+        assert_eq!(downgrade_sgr("38;2;255;0;0", Ansi256), "38;5;196");
+        assert_eq!(downgrade_sgr("38;2;255;0;0", Ansi16), "91");
+        assert_eq!(downgrade_sgr("48;2;0;0;128", Ansi16), "44");
+        assert_eq!(downgrade_sgr("1;38;2;255;0;0", None), "1");
+        assert_eq!(downgrade_sgr("38;5;232", Ansi16), "30");
+        assert_eq!(downgrade_sgr("48;5;9", Ansi16), "101");
+        assert_eq!(downgrade_sgr("48;5;9", Ansi256), "48;5;9");
+        assert_eq!(downgrade_sgr("31;44", Ansi16), "31;44");
+        assert_eq!(downgrade_sgr("31;44", None), "");
+        assert_eq!(downgrade_sgr("4;58;2;0;0;0", Ansi16), "4");
+        assert_eq!(downgrade_sgr("0", None), "0");
+    }
+
+    #[test]
+    fn rgb_to_ansi256_test() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(95, 135, 175), 67);
+    }
+
+    #[test]
+    fn color_depth_writer_test() {
+        use ColorDepth::*;
+
+        assert_eq!(
+            downgrade("\u{1b}[38;2;255;0;0mred\u{1b}[39m", Ansi16),
+            "\u{1b}[91mred\u{1b}[39m"
+        );
+        assert_eq!(downgrade("\u{1b}[38;2;255;0;0mred\u{1b}[39m", None), "red");
+        assert_eq!(
+            downgrade("\u{1b}[1;31mbold\u{1b}[0m", None),
+            "\u{1b}[1mbold\u{1b}[0m"
+        );
+        assert_eq!(downgrade("\u{1b}[mreset", None), "\u{1b}[mreset");
+        assert_eq!(downgrade("\u{1b}[2Kline", None), "\u{1b}[2Kline");
+        assert_eq!(
+            downgrade("\u{1b}]8;;https://example.com\u{1b}\\link", None),
+            "\u{1b}]8;;https://example.com\u{1b}\\link"
+        );
+    }
+
+    #[test]
+    fn color_depth_writer_split_sequence_test() {
+        let mut buf = String::new();
+        let mut writer = ColorDepthWriter::new(&mut buf, ColorDepth::Ansi256);
+        writer.write_str("a\u{1b}[38;2;").unwrap();
+        writer.write_str("255;0;0mb\u{1b}").unwrap();
+        writer.write_str("[39m").unwrap();
+        writer.finish().unwrap();
+
+        assert_eq!(buf, "a\u{1b}[38;5;196mb\u{1b}[39m");
+    }
+}
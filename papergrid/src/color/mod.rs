@@ -2,12 +2,27 @@
 
 #[cfg(feature = "std")]
 mod ansi_color;
+#[cfg(feature = "std")]
+mod color_depth;
+#[cfg(feature = "std")]
+mod sgr;
 mod static_color;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use ansi_color::AnsiColor;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use color_depth::ColorDepth;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use sgr::{split_ansi, strip_ansi, AnsiPart, SgrColor, SgrStyle};
+
+#[cfg(feature = "std")]
+pub(crate) use color_depth::ColorDepthWriter;
+
 pub use static_color::StaticColor;
 
 use core::fmt::{self, Write};
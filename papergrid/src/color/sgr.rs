@@ -0,0 +1,234 @@
+use std::convert::TryFrom;
+
+use super::color_depth::{BASIC_COLORS, CUBE_LEVELS};
+
+use crate::util::string::find_osc_end;
+
+/// A color set by an SGR sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SgrColor {
+    /// One of 16 basic colors (`30..=37`, `90..=97` and their background counterparts).
+    ///
+    /// Bright colors go after normal ones, so it's in range `0..16`.
+    Basic(u8),
+    /// A color of a 256 color palette (`38;5;n`).
+    Indexed(u8),
+    /// A 24 bit color (`38;2;r;g;b`).
+    Rgb(u8, u8, u8),
+}
+
+impl SgrColor {
+    /// Returns an RGB value of the color using a default xterm palette.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            SgrColor::Basic(n) => BASIC_COLORS[usize::from(n % 16)],
+            SgrColor::Indexed(n @ 0..=15) => BASIC_COLORS[usize::from(n)],
+            SgrColor::Indexed(n @ 16..=231) => {
+                let n = n - 16;
+                let level = |c: u8| CUBE_LEVELS[usize::from(c)];
+                (level(n / 36), level(n / 6 % 6), level(n % 6))
+            }
+            SgrColor::Indexed(n) => {
+                let level = 8 + (n - 232) * 10;
+                (level, level, level)
+            }
+            SgrColor::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+}
+
+/// A state of SGR (Select Graphic Rendition) attributes.
+///
+/// It can be used to convert ANSI colored text into other formats.
+///
+/// ```
+/// use papergrid::color::{SgrColor, SgrStyle};
+///
+/// let style = SgrStyle::parse("\u{1b}[1;31m\u{1b}[48;2;1;2;3m");
+///
+/// assert!(style.bold);
+/// assert_eq!(style.fg, Some(SgrColor::Basic(1)));
+/// assert_eq!(style.bg, Some(SgrColor::Rgb(1, 2, 3)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SgrStyle {
+    /// A foreground color.
+    pub fg: Option<SgrColor>,
+    /// A background color.
+    pub bg: Option<SgrColor>,
+    /// Bold (`1`).
+    pub bold: bool,
+    /// Dim (`2`).
+    pub dim: bool,
+    /// Italic (`3`).
+    pub italic: bool,
+    /// Underline (`4`).
+    pub underline: bool,
+    /// Blink (`5` or `6`).
+    pub blink: bool,
+    /// Reversed colors (`7`).
+    pub reversed: bool,
+    /// Hidden text (`8`).
+    pub hidden: bool,
+    /// Strikethrough (`9`).
+    pub strikethrough: bool,
+}
+
+impl SgrStyle {
+    /// Parses all `ESC[...m` sequences in a string, applying them one by one.
+    pub fn parse(text: &str) -> Self {
+        let mut style = Self::default();
+        style.update(text);
+
+        style
+    }
+
+    /// Applies all `ESC[...m` sequences in a string to the style.
+    pub fn update(&mut self, text: &str) {
+        for part in split_ansi(text) {
+            if let AnsiPart::Sgr(params) = part {
+                self.apply(params);
+            }
+        }
+    }
+
+    /// Applies a list of SGR parameters, e.g. `1;31`.
+    pub fn apply(&mut self, params: &str) {
+        let mut params = params.split(';').map(|p| {
+            if p.is_empty() {
+                0
+            } else {
+                p.parse().unwrap_or(u16::MAX)
+            }
+        });
+
+        while let Some(p) = params.next() {
+            match p {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                5 | 6 => self.blink = true,
+                7 => self.reversed = true,
+                8 => self.hidden = true,
+                9 => self.strikethrough = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                25 => self.blink = false,
+                27 => self.reversed = false,
+                28 => self.hidden = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.fg = Some(SgrColor::Basic((p - 30) as u8)),
+                90..=97 => self.fg = Some(SgrColor::Basic((p - 90 + 8) as u8)),
+                40..=47 => self.bg = Some(SgrColor::Basic((p - 40) as u8)),
+                100..=107 => self.bg = Some(SgrColor::Basic((p - 100 + 8) as u8)),
+                38 => self.fg = parse_extended_color(&mut params),
+                48 => self.bg = parse_extended_color(&mut params),
+                39 => self.fg = None,
+                49 => self.bg = None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Checks whether no attributes are set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn parse_extended_color(params: &mut impl Iterator<Item = u16>) -> Option<SgrColor> {
+    match params.next()? {
+        5 => {
+            let n = params.next()?;
+            u8::try_from(n).ok().map(SgrColor::Indexed)
+        }
+        2 => {
+            let r = params.next()?.min(255) as u8;
+            let g = params.next()?.min(255) as u8;
+            let b = params.next()?.min(255) as u8;
+            Some(SgrColor::Rgb(r, g, b))
+        }
+        _ => None,
+    }
+}
+
+/// A part of a text split by ANSI escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiPart<'a> {
+    /// A plain text.
+    Text(&'a str),
+    /// Parameters of an `ESC[...m` sequence.
+    Sgr(&'a str),
+}
+
+/// Splits a text into a plain text and SGR parameters.
+///
+/// Other escape sequences (like `OSC 8` hyperlinks) are dropped.
+///
+/// ```
+/// use papergrid::color::{split_ansi, AnsiPart};
+///
+/// assert_eq!(
+///     split_ansi("\u{1b}[31mred\u{1b}[0m"),
+///     [AnsiPart::Sgr("31"), AnsiPart::Text("red"), AnsiPart::Sgr("0")],
+/// );
+/// ```
+pub fn split_ansi(text: &str) -> Vec<AnsiPart<'_>> {
+    let mut parts = Vec::new();
+
+    let mut rest = text;
+    while let Some(start) = rest.find('\u{1b}') {
+        if start > 0 {
+            parts.push(AnsiPart::Text(&rest[..start]));
+        }
+
+        rest = &rest[start + 1..];
+
+        if let Some(seq) = rest.strip_prefix('[') {
+            // CSI: parameters are followed by a final byte in range `@`..=`~`
+            match seq.find(|c: char| ('@'..='~').contains(&c)) {
+                Some(end) => {
+                    if seq[end..].starts_with('m') {
+                        parts.push(AnsiPart::Sgr(&seq[..end]));
+                    }
+
+                    rest = &seq[end + 1..];
+                }
+                None => rest = "",
+            }
+        } else if let Some(seq) = rest.strip_prefix(']') {
+            rest = match find_osc_end(seq) {
+                Some(end) => &seq[end..],
+                None => "",
+            };
+        } else {
+            // a 2 byte sequence, e.g. `ESC \`
+            let mut chars = rest.chars();
+            chars.next();
+            rest = chars.as_str();
+        }
+    }
+
+    if !rest.is_empty() {
+        parts.push(AnsiPart::Text(rest));
+    }
+
+    parts
+}
+
+/// Removes all ANSI escape sequences from a text.
+pub fn strip_ansi(text: &str) -> String {
+    split_ansi(text)
+        .into_iter()
+        .filter_map(|part| match part {
+            AnsiPart::Text(text) => Some(text),
+            AnsiPart::Sgr(_) => None,
+        })
+        .collect()
+}
@@ -9,11 +9,12 @@ mod offset;
 
 use std::collections::HashMap;
 
-use crate::color::{AnsiColor, StaticColor};
+use crate::color::{AnsiColor, ColorDepth, StaticColor};
 use crate::config::{
     AlignmentHorizontal, AlignmentVertical, Border, Borders, Entity, Indent, Position, Sides,
 };
 use crate::grid::compact::CompactConfig;
+use crate::util::string::AmbiguousWidth;
 use borders_config::BordersConfig;
 
 pub use self::{entity_map::EntityMap, formatting::Formatting, offset::Offset};
@@ -35,6 +36,10 @@ pub struct GridConfig {
     override_horizontal_borders: HashMap<Position, HashMap<Offset, char>>,
     override_vertical_borders: HashMap<Position, HashMap<Offset, char>>,
     border_colors: BordersConfig<AnsiColor<'static>>,
+    backgrounds: EntityMap<Option<AnsiColor<'static>>>,
+    row_colors: HashMap<usize, AnsiColor<'static>>,
+    color_depth: ColorDepth,
+    ambiguous_width: AmbiguousWidth,
 }
 
 impl Default for GridConfig {
@@ -52,6 +57,10 @@ impl Default for GridConfig {
             override_horizontal_borders: HashMap::default(),
             override_vertical_borders: HashMap::default(),
             border_colors: BordersConfig::default(),
+            backgrounds: EntityMap::default(),
+            row_colors: HashMap::default(),
+            color_depth: ColorDepth::TrueColor,
+            ambiguous_width: AmbiguousWidth::Narrow,
         }
     }
 }
@@ -307,6 +316,68 @@ impl GridConfig {
         self.alignment_h.get(entity)
     }
 
+    /// Set a background color to a given cells.
+    ///
+    /// Unlike a text color, the background fills the whole cell,
+    /// including its padding and an alignment indent.
+    pub fn set_background(&mut self, entity: Entity, color: AnsiColor<'static>) {
+        self.backgrounds.insert(entity, Some(color));
+    }
+
+    /// Removes a background color from a given cells.
+    pub fn remove_background(&mut self, entity: Entity) {
+        self.backgrounds.insert(entity, None);
+    }
+
+    /// Get a background color for a given [Entity].
+    pub fn get_background(&self, entity: Entity) -> Option<&AnsiColor<'static>> {
+        self.backgrounds.get(entity).as_ref()
+    }
+
+    /// Set a color of a whole row.
+    ///
+    /// It fills all cells of the row which don't have their own background,
+    /// and the vertical borders between them.
+    /// Horizontal lines and a margin are not colored.
+    pub fn set_row_color(&mut self, row: usize, color: AnsiColor<'static>) {
+        self.row_colors.insert(row, color);
+    }
+
+    /// Removes a color of a whole row.
+    pub fn remove_row_color(&mut self, row: usize) {
+        self.row_colors.remove(&row);
+    }
+
+    /// Get a color of a whole row, if any is set.
+    pub fn get_row_color(&self, row: usize) -> Option<&AnsiColor<'static>> {
+        self.row_colors.get(&row)
+    }
+
+    /// Set a color depth a grid is rendered with.
+    ///
+    /// Colors which are not supported by the depth are downgraded,
+    /// including the ones which are part of a cell content.
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
+    }
+
+    /// Get a color depth a grid is rendered with.
+    pub fn get_color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Set a width of East Asian ambiguous characters, like box-drawing characters.
+    ///
+    /// It's used to estimate a grid dimension and to draw borders.
+    pub fn set_ambiguous_width(&mut self, width: AmbiguousWidth) {
+        self.ambiguous_width = width;
+    }
+
+    /// Get a width of East Asian ambiguous characters.
+    pub fn get_ambiguous_width(&self) -> AmbiguousWidth {
+        self.ambiguous_width
+    }
+
     /// Set border set a border value to all cells in [`Entity`].
     pub fn set_border(&mut self, pos: Position, border: Border<char>) {
         self.borders.insert_border(pos, border);
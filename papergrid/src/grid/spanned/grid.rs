@@ -8,12 +8,15 @@ use std::{
 };
 
 use crate::{
-    color::{AnsiColor, Color},
+    color::{AnsiColor, Color, ColorDepth, ColorDepthWriter},
     colors::Colors,
     config::{AlignmentHorizontal, AlignmentVertical, Indent, Position, Sides},
     dimension::Dimension,
     records::Records,
-    util::string::{count_lines, get_lines, string_width, string_width_multiline, Lines},
+    util::string::{
+        char_width_ambiguous, count_lines, get_lines, string_width_ambiguous,
+        string_width_multiline_ambiguous, AmbiguousWidth, Lines,
+    },
 };
 
 use super::config::{ColoredIndent, Formatting, GridConfig, Offset};
@@ -54,7 +57,15 @@ impl<R, D, G, C> Grid<R, D, G, C> {
         }
 
         let config = self.config.borrow();
-        print_grid(&mut f, self.records, config, &self.dimension, &self.colors)
+
+        let depth = config.get_color_depth();
+        if depth == ColorDepth::TrueColor {
+            return print_grid(&mut f, self.records, config, &self.dimension, &self.colors);
+        }
+
+        let mut f = ColorDepthWriter::new(f, depth);
+        print_grid(&mut f, self.records, config, &self.dimension, &self.colors)?;
+        f.finish()
     }
 
     /// Builds a table into string.
@@ -257,17 +268,19 @@ where
     D: Dimension,
     C: Colors,
 {
+    let row_color = cfg.get_row_color(row);
+
     print_margin_left(f, cfg, line, totalh)?;
 
     for (col, cell) in columns.enumerate() {
         let pos = (row, col);
         let width = dims.get_width(col);
         let color = colors.get_color(pos);
-        print_vertical_char(f, cfg, pos, 0, 1, shape)?;
-        print_single_line_column(f, cell.as_ref(), cfg, width, color, pos)?;
+        print_vertical_char(f, cfg, pos, 0, 1, shape, row_color)?;
+        print_single_line_column(f, cell.as_ref(), cfg, width, color, row_color, pos)?;
     }
 
-    print_vertical_char(f, cfg, (row, shape.1), 0, 1, shape)?;
+    print_vertical_char(f, cfg, (row, shape.1), 0, 1, shape, row_color)?;
 
     print_margin_right(f, cfg, line, totalh)?;
 
@@ -280,37 +293,32 @@ fn print_single_line_column<F: Write, C: Color>(
     cfg: &GridConfig,
     width: usize,
     color: Option<&C>,
+    row_color: Option<&AnsiColor<'static>>,
     pos: Position,
 ) -> fmt::Result {
     let pos = pos.into();
     let pad = cfg.get_padding(pos);
     let fmt = cfg.get_formatting(pos);
+    let background = cfg.get_background(pos).or(row_color);
+    let ambiguous = cfg.get_ambiguous_width();
 
     let (text, text_width) = if fmt.horizontal_trim && !text.is_empty() {
         let text = string_trim(text);
-        let width = string_width(&text);
+        let width = string_width_ambiguous(&text, ambiguous);
 
         (text, width)
     } else {
         let text = Cow::Borrowed(text);
-        let width = string_width_multiline(&text);
+        let width = string_width_multiline_ambiguous(&text, ambiguous);
 
         (text, width)
     };
 
     let alignment = *cfg.get_alignment_horizontal(pos);
     let available_width = width - pad.left.indent.size - pad.right.indent.size;
-    let (left, right) = calculate_indent(alignment, text_width, available_width);
-
-    print_padding(f, &pad.left)?;
-
-    repeat_char(f, ' ', left)?;
-    print_text(f, &text, color)?;
-    repeat_char(f, ' ', right)?;
+    let indent = calculate_indent(alignment, text_width, available_width);
 
-    print_padding(f, &pad.right)?;
-
-    Ok(())
+    print_cell_line(f, &text, pad, indent, color, background)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -324,17 +332,19 @@ fn print_columns_lines<T, F: Write, C: Color>(
     totalh: Option<usize>,
     shape: (usize, usize),
 ) -> fmt::Result {
+    let row_color = cfg.get_row_color(row);
+
     for i in 0..height {
         let exact_line = line + i;
 
         print_margin_left(f, cfg, exact_line, totalh)?;
 
         for (col, cell) in buf.iter_mut().enumerate() {
-            print_vertical_char(f, cfg, (row, col), i, height, shape)?;
-            cell.display(f)?;
+            print_vertical_char(f, cfg, (row, col), i, height, shape, row_color)?;
+            cell.display(f, row_color)?;
         }
 
-        print_vertical_char(f, cfg, (row, shape.1), i, height, shape)?;
+        print_vertical_char(f, cfg, (row, shape.1), i, height, shape, row_color)?;
 
         print_margin_right(f, cfg, exact_line, totalh)?;
 
@@ -516,7 +526,7 @@ fn print_split_line_spanned<S, F: Write, D: Dimension, C: Color>(
             // so. we just need to use line from other cell.
 
             let (cell, _, _) = buf.get_mut(&col).unwrap();
-            cell.display(f)?;
+            cell.display(f, None)?;
 
             // We need to use a correct right split char.
             let original_row = closest_visible_row(cfg, pos).unwrap();
@@ -669,6 +679,8 @@ where
         buf.insert(col, (cell, rowspan, colspan));
     }
 
+    let row_color = cfg.get_row_color(row);
+
     for i in 0..this_height {
         let exact_line = line + i;
         let cell_line = i;
@@ -676,11 +688,19 @@ where
         print_margin_left(f, cfg, exact_line, totalh)?;
 
         for (&col, (cell, _, _)) in buf.iter_mut() {
-            print_vertical_char(f, cfg, (row, col), cell_line, this_height, shape)?;
-            cell.display(f)?;
+            print_vertical_char(f, cfg, (row, col), cell_line, this_height, shape, row_color)?;
+            cell.display(f, row_color)?;
         }
 
-        print_vertical_char(f, cfg, (row, shape.1), cell_line, this_height, shape)?;
+        print_vertical_char(
+            f,
+            cfg,
+            (row, shape.1),
+            cell_line,
+            this_height,
+            shape,
+            row_color,
+        )?;
 
         print_margin_right(f, cfg, exact_line, totalh)?;
 
@@ -710,7 +730,7 @@ fn print_horizontal_border<F: Write>(
             f.write_char(c)?;
         }
     } else {
-        repeat_char(f, c, width)?;
+        repeat_char_ambiguous(f, c, width, cfg.get_ambiguous_width())?;
     }
 
     Ok(())
@@ -725,6 +745,8 @@ struct Cell<T, C> {
     fmt: Formatting,
     pad: Sides<ColoredIndent>,
     color: Option<C>,
+    background: Option<AnsiColor<'static>>,
+    ambiguous: AmbiguousWidth,
 }
 
 impl<T, C> Cell<T, C>
@@ -743,6 +765,12 @@ where
         let pad = cfg.get_padding(pos.into()).clone();
         let alignh = *cfg.get_alignment_horizontal(pos.into());
         let alignv = *cfg.get_alignment_vertical(pos.into());
+        let ambiguous = cfg.get_ambiguous_width();
+        // a row color is taken by a cell so a row spanned cell keeps it on other rows.
+        let background = cfg
+            .get_background(pos.into())
+            .or_else(|| cfg.get_row_color(pos.0))
+            .cloned();
 
         let (count_lines, skip) = if fmt.vertical_trim {
             let (len, top, _) = count_empty_lines(text.as_ref());
@@ -755,7 +783,7 @@ where
 
         let mut indent_left = None;
         if !fmt.allow_lines_alignment {
-            let text_width = get_text_width(text.as_ref(), fmt.horizontal_trim);
+            let text_width = get_text_width(text.as_ref(), fmt.horizontal_trim, ambiguous);
             let available = width - pad.left.indent.size - pad.right.indent.size;
             indent_left = Some(calculate_indent(alignh, text_width, available).0);
         }
@@ -774,6 +802,8 @@ where
             fmt,
             pad,
             color,
+            background,
+            ambiguous,
         }
     }
 }
@@ -782,17 +812,19 @@ impl<T, C> Cell<T, C>
 where
     C: Color,
 {
-    fn display<F: Write>(&mut self, f: &mut F) -> fmt::Result {
+    fn display<F: Write>(&mut self, f: &mut F, row_color: Option<&AnsiColor<'_>>) -> fmt::Result {
+        let background = self.background.as_ref().or(row_color);
+
         if self.indent_top > 0 {
             self.indent_top -= 1;
-            print_padding_n(f, &self.pad.top, self.width)?;
+            print_padding_n(f, &self.pad.top, self.width, background)?;
             return Ok(());
         }
 
         let line = match self.lines.lines.next() {
             Some(line) => line,
             None => {
-                print_padding_n(f, &self.pad.bottom, self.width)?;
+                print_padding_n(f, &self.pad.bottom, self.width, background)?;
                 return Ok(());
             }
         };
@@ -803,25 +835,17 @@ where
             line
         };
 
-        let line_width = string_width(&line);
+        let line_width = string_width_ambiguous(&line, self.ambiguous);
         let available_width = self.width - self.pad.left.indent.size - self.pad.right.indent.size;
 
-        let (left, right) = if self.fmt.allow_lines_alignment {
+        let indent = if self.fmt.allow_lines_alignment {
             calculate_indent(self.alignh, line_width, available_width)
         } else {
             let left = self.indent_left.expect("must be here");
             (left, available_width - line_width - left)
         };
 
-        print_padding(f, &self.pad.left)?;
-
-        repeat_char(f, ' ', left)?;
-        print_text(f, &line, self.color.as_ref())?;
-        repeat_char(f, ' ', right)?;
-
-        print_padding(f, &self.pad.right)?;
-
-        Ok(())
+        print_cell_line(f, &line, &self.pad, indent, self.color.as_ref(), background)
     }
 }
 
@@ -862,12 +886,51 @@ impl<C> LinesIter<C> {
     }
 }
 
-fn print_text<F: Write>(f: &mut F, text: &str, clr: Option<impl Color>) -> fmt::Result {
+/// Prints a line of a cell, filling it with a background color if it's set.
+fn print_cell_line<F: Write>(
+    f: &mut F,
+    text: &str,
+    pad: &Sides<ColoredIndent>,
+    (left, right): (usize, usize),
+    clr: Option<impl Color>,
+    background: Option<&AnsiColor<'_>>,
+) -> fmt::Result {
+    if let Some(background) = background {
+        background.fmt_prefix(f)?;
+    }
+
+    print_padding(f, &pad.left, background)?;
+
+    repeat_char(f, ' ', left)?;
+    print_text(f, text, clr, background)?;
+    repeat_char(f, ' ', right)?;
+
+    print_padding(f, &pad.right, background)?;
+
+    if let Some(background) = background {
+        background.fmt_suffix(f)?;
+    }
+
+    Ok(())
+}
+
+fn print_text<F: Write>(
+    f: &mut F,
+    text: &str,
+    clr: Option<impl Color>,
+    background: Option<&AnsiColor<'_>>,
+) -> fmt::Result {
     match clr {
         Some(color) => {
             color.fmt_prefix(f)?;
             f.write_str(text)?;
-            color.fmt_suffix(f)
+            color.fmt_suffix(f)?;
+
+            // a suffix may reset a background so we set it back.
+            match background {
+                Some(background) => background.fmt_prefix(f),
+                None => Ok(()),
+            }
         }
         None => f.write_str(text),
     }
@@ -940,10 +1003,29 @@ fn calculate_indent(
 }
 
 fn repeat_char<F: Write>(f: &mut F, c: char, n: usize) -> fmt::Result {
-    for _ in 0..n {
+    repeat_char_ambiguous(f, c, n, AmbiguousWidth::Narrow)
+}
+
+/// Repeats a char to fill `n` columns.
+///
+/// In case a char is wider than 1 column (e.g. an ambiguous box-drawing char in a CJK terminal)
+/// a rest which can't be filled by it is filled with spaces.
+fn repeat_char_ambiguous<F: Write>(
+    f: &mut F,
+    c: char,
+    n: usize,
+    ambiguous: AmbiguousWidth,
+) -> fmt::Result {
+    let width = char_width_ambiguous(c, ambiguous).max(1);
+
+    for _ in 0..n / width {
         f.write_char(c)?;
     }
 
+    for _ in 0..n % width {
+        f.write_char(' ')?;
+    }
+
     Ok(())
 }
 
@@ -954,6 +1036,7 @@ fn print_vertical_char<F: Write>(
     line: usize,
     count_lines: usize,
     shape: (usize, usize),
+    row_color: Option<&AnsiColor<'_>>,
 ) -> fmt::Result {
     let symbol = match cfg.get_vertical(pos, shape.1) {
         Some(c) => c,
@@ -966,6 +1049,10 @@ fn print_vertical_char<F: Write>(
         .flatten()
         .unwrap_or(symbol);
 
+    if let Some(clr) = row_color {
+        clr.fmt_prefix(f)?;
+    }
+
     match cfg.get_vertical_color(pos, shape.1) {
         Some(clr) => {
             clr.fmt_prefix(f)?;
@@ -975,6 +1062,10 @@ fn print_vertical_char<F: Write>(
         None => f.write_char(symbol)?,
     }
 
+    if let Some(clr) = row_color {
+        clr.fmt_suffix(f)?;
+    }
+
     Ok(())
 }
 
@@ -1096,12 +1187,34 @@ fn print_indent_lines<F: Write>(
     Ok(())
 }
 
-fn print_padding<F: Write>(f: &mut F, pad: &ColoredIndent) -> fmt::Result {
-    print_indent(f, pad.indent.fill, pad.indent.size, pad.color.as_ref())
+fn print_padding<F: Write>(
+    f: &mut F,
+    pad: &ColoredIndent,
+    background: Option<&AnsiColor<'_>>,
+) -> fmt::Result {
+    print_indent(f, pad.indent.fill, pad.indent.size, pad.color.as_ref())?;
+
+    // a suffix of a padding color may reset a background so we set it back.
+    match (&pad.color, background) {
+        (Some(_), Some(background)) => background.fmt_prefix(f),
+        _ => Ok(()),
+    }
 }
 
-fn print_padding_n<F: Write>(f: &mut F, pad: &ColoredIndent, n: usize) -> fmt::Result {
-    print_indent(f, pad.indent.fill, n, pad.color.as_ref())
+fn print_padding_n<F: Write>(
+    f: &mut F,
+    pad: &ColoredIndent,
+    n: usize,
+    background: Option<&AnsiColor<'_>>,
+) -> fmt::Result {
+    match background {
+        Some(background) => {
+            background.fmt_prefix(f)?;
+            print_indent(f, pad.indent.fill, n, pad.color.as_ref())?;
+            background.fmt_suffix(f)
+        }
+        None => print_indent(f, pad.indent.fill, n, pad.color.as_ref()),
+    }
 }
 
 fn print_indent<F: Write>(
@@ -1226,14 +1339,14 @@ fn count_empty_lines(cell: &str) -> (usize, usize, usize) {
     (len, top, bottom)
 }
 
-fn get_text_width(text: &str, trim: bool) -> usize {
+fn get_text_width(text: &str, trim: bool, ambiguous: AmbiguousWidth) -> usize {
     if trim {
         get_lines(text)
-            .map(|line| string_width(line.trim()))
+            .map(|line| string_width_ambiguous(line.trim(), ambiguous))
             .max()
             .unwrap_or(0)
     } else {
-        string_width_multiline(text)
+        string_width_multiline_ambiguous(text, ambiguous)
     }
 }
 
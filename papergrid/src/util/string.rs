@@ -4,36 +4,80 @@
 //!
 //! [`Grid`]: crate::grid::spanned::Grid
 
+/// A width of East Asian ambiguous characters,
+/// like box-drawing characters, `±` or `§`.
+///
+/// Terminals configured for CJK locales usually render them as 2 columns,
+/// while others render them as 1 column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters take 1 column.
+    Narrow,
+    /// Ambiguous characters take 2 columns.
+    Wide,
+}
+
 /// Returns string width and count lines of a string. It's a combination of [`string_width_multiline_tab`] and [`count_lines`].
 #[cfg(feature = "std")]
 pub fn string_dimension(text: &str) -> (usize, usize) {
-    #[cfg(not(feature = "color"))]
-    {
+    string_dimension_ambiguous(text, AmbiguousWidth::Narrow)
+}
+
+/// Returns string width and count lines of a string,
+/// using a given width of East Asian ambiguous characters.
+#[cfg(feature = "std")]
+pub fn string_dimension_ambiguous(text: &str, ambiguous: AmbiguousWidth) -> (usize, usize) {
+    #[cfg(all(not(feature = "color"), not(feature = "grapheme")))]
+    if !text.contains("\u{1b}]") {
         let (lines, acc, max) = text.chars().fold((1, 0, 0), |(lines, acc, max), c| {
             if c == '\n' {
                 (lines + 1, 0, acc.max(max))
             } else {
-                let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                (lines, acc + w, max)
+                (lines, acc + char_width_ambiguous(c, ambiguous), max)
             }
         });
 
-        (lines, acc.max(max))
+        return (lines, acc.max(max));
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        text.split('\n')
+            .map(|line| string_width_ambiguous(line, ambiguous))
+            .fold((0, 0), |(i, acc), width| (i + 1, acc.max(width)))
     }
 
     #[cfg(feature = "color")]
     {
         get_lines(text)
-            .map(|line| string_width(&line))
+            .map(|line| string_width_ambiguous(&line, ambiguous))
             .fold((0, 0), |(i, acc), width| (i + 1, acc.max(width)))
     }
 }
 
 /// Returns a string width.
 pub fn string_width(text: &str) -> usize {
+    string_width_ambiguous(text, AmbiguousWidth::Narrow)
+}
+
+/// Returns a string width, using a given width of East Asian ambiguous characters.
+pub fn string_width_ambiguous(text: &str, ambiguous: AmbiguousWidth) -> usize {
     #[cfg(not(feature = "color"))]
     {
-        unicode_width::UnicodeWidthStr::width(text)
+        // OSC sequences (e.g. terminal hyperlinks) are not visible.
+        let mut width = 0;
+        let mut rest = text;
+        while let Some(start) = rest.find("\u{1b}]") {
+            let end = match find_osc_end(&rest[start + 2..]) {
+                Some(end) => start + 2 + end,
+                None => break,
+            };
+
+            width += text_width(&rest[..start], ambiguous);
+            rest = &rest[end..];
+        }
+
+        width + text_width(rest, ambiguous)
     }
 
     #[cfg(feature = "color")]
@@ -43,28 +87,150 @@ pub fn string_width(text: &str) -> usize {
 
         ansitok::parse_ansi(text)
             .filter(|e| e.kind() == ansitok::ElementKind::Text)
-            .map(|e| &text[e.start()..e.end()])
-            .map(unicode_width::UnicodeWidthStr::width)
+            .map(|e| text_width(&text[e.start()..e.end()], ambiguous))
             .sum()
     }
 }
 
 /// Returns a max string width of a line.
 pub fn string_width_multiline(text: &str) -> usize {
-    #[cfg(not(feature = "color"))]
+    string_width_multiline_ambiguous(text, AmbiguousWidth::Narrow)
+}
+
+/// Returns a max string width of a line,
+/// using a given width of East Asian ambiguous characters.
+pub fn string_width_multiline_ambiguous(text: &str, ambiguous: AmbiguousWidth) -> usize {
+    text.lines()
+        .map(|line| string_width_ambiguous(line, ambiguous))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns an iterator over graphemes of a string alongside their widths.
+///
+/// By default every char is considered to be a separate grapheme.
+/// With a `grapheme` feature a string is split by extended grapheme clusters,
+/// so emoji sequences, flags and combining marks are measured and split as a whole.
+pub fn graphemes(text: &str) -> impl Iterator<Item = (&str, usize)> + '_ {
+    graphemes_ambiguous(text, AmbiguousWidth::Narrow)
+}
+
+/// Returns an iterator over graphemes of a string alongside their widths,
+/// using a given width of East Asian ambiguous characters.
+///
+/// See [`graphemes`].
+#[cfg(not(feature = "grapheme"))]
+pub fn graphemes_ambiguous(
+    text: &str,
+    ambiguous: AmbiguousWidth,
+) -> impl Iterator<Item = (&str, usize)> + '_ {
+    text.char_indices().map(move |(i, c)| {
+        let width = char_width_ambiguous(c, ambiguous);
+        (&text[i..i + c.len_utf8()], width)
+    })
+}
+
+/// Returns an iterator over graphemes of a string alongside their widths,
+/// using a given width of East Asian ambiguous characters.
+///
+/// See [`graphemes`].
+#[cfg(feature = "grapheme")]
+pub fn graphemes_ambiguous(
+    text: &str,
+    ambiguous: AmbiguousWidth,
+) -> impl Iterator<Item = (&str, usize)> + '_ {
+    unicode_segmentation::UnicodeSegmentation::graphemes(text, true)
+        .map(move |g| (g, grapheme_width(g, ambiguous)))
+}
+
+/// Returns a char width.
+pub fn char_width(c: char) -> usize {
+    char_width_ambiguous(c, AmbiguousWidth::Narrow)
+}
+
+/// Returns a char width, using a given width of East Asian ambiguous characters.
+///
+/// ```
+/// use papergrid::util::string::{char_width_ambiguous, AmbiguousWidth};
+///
+/// assert_eq!(char_width_ambiguous('±', AmbiguousWidth::Narrow), 1);
+/// assert_eq!(char_width_ambiguous('±', AmbiguousWidth::Wide), 2);
+/// ```
+pub fn char_width_ambiguous(c: char, ambiguous: AmbiguousWidth) -> usize {
+    let width = match ambiguous {
+        AmbiguousWidth::Narrow => unicode_width::UnicodeWidthChar::width(c),
+        AmbiguousWidth::Wide => unicode_width::UnicodeWidthChar::width_cjk(c),
+    };
+
+    width.unwrap_or(0)
+}
+
+/// Returns a position right after an end of an OSC sequence,
+/// which is terminated either by `ST` (`ESC \\`) or `BEL`.
+///
+/// A given text is expected to go right after an `ESC ]` introducer.
+///
+/// ```
+/// use papergrid::util::string::find_osc_end;
+///
+/// assert_eq!(find_osc_end("8;;url\u{1b}\\text"), Some(8));
+/// assert_eq!(find_osc_end("8;;url\u{7}text"), Some(7));
+/// assert_eq!(find_osc_end("8;;url"), None);
+/// ```
+pub fn find_osc_end(seq: &str) -> Option<usize> {
+    let end = seq.find(['\u{7}', '\u{1b}'])?;
+    if seq[end..].starts_with('\u{7}') {
+        Some(end + 1)
+    } else if seq[end..].starts_with("\u{1b}\\") {
+        Some(end + 2)
+    } else {
+        None
+    }
+}
+
+/// Returns a width of a text which has no ANSI sequences.
+fn text_width(text: &str, ambiguous: AmbiguousWidth) -> usize {
+    #[cfg(not(feature = "grapheme"))]
     {
-        text.lines()
-            .map(unicode_width::UnicodeWidthStr::width)
-            .max()
-            .unwrap_or(0)
+        match ambiguous {
+            AmbiguousWidth::Narrow => unicode_width::UnicodeWidthStr::width(text),
+            AmbiguousWidth::Wide => unicode_width::UnicodeWidthStr::width_cjk(text),
+        }
     }
 
-    #[cfg(feature = "color")]
+    #[cfg(feature = "grapheme")]
     {
-        text.lines().map(string_width).max().unwrap_or(0)
+        graphemes_ambiguous(text, ambiguous)
+            .map(|(_, width)| width)
+            .sum()
     }
 }
 
+/// Returns a width of an extended grapheme cluster.
+///
+/// A cluster takes a width of its widest char,
+/// emoji presentation sequences and flags take 2 columns.
+#[cfg(feature = "grapheme")]
+fn grapheme_width(g: &str, ambiguous: AmbiguousWidth) -> usize {
+    const EMOJI_PRESENTATION: char = '\u{FE0F}';
+
+    let is_wide = g.contains(EMOJI_PRESENTATION) || g.starts_with(is_regional_indicator);
+    if is_wide {
+        return 2;
+    }
+
+    g.chars()
+        .map(|c| char_width_ambiguous(c, ambiguous))
+        .max()
+        .unwrap_or(0)
+        .min(2)
+}
+
+#[cfg(feature = "grapheme")]
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
 /// Calculates a number of lines.
 pub fn count_lines(s: &str) -> usize {
     if s.is_empty() {
@@ -191,6 +357,31 @@ mod tests {
         assert_eq!(string_width_multiline("Go 👍\nC 😎"), 5);
     }
 
+    #[cfg(not(feature = "color"))]
+    #[test]
+    fn hyperlink_string_width_test() {
+        let link = "\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\";
+        assert_eq!(string_width(link), 4);
+        assert_eq!(
+            string_width("\u{1b}]8;;https://example.com\u{7}link\u{1b}]8;;\u{7}"),
+            4
+        );
+        assert_eq!(string_width_multiline(&format!("{}\nab", link)), 4);
+        assert_eq!(string_dimension(&format!("{}\nab", link)), (2, 4));
+    }
+
+    #[cfg(feature = "grapheme")]
+    #[test]
+    fn grapheme_string_width_test() {
+        assert_eq!(string_width("👨‍👩‍👧"), 2);
+        assert_eq!(string_width("🇺🇦🇩🇪"), 4);
+        assert_eq!(string_width("e\u{301}"), 1);
+        assert_eq!(string_width("❤\u{fe0f}"), 2);
+        assert_eq!(string_width_multiline("👍🏽\nab"), 2);
+        assert_eq!(string_dimension("👨‍👩‍👧\n🇺🇦🇩🇪"), (2, 4));
+        assert_eq!(graphemes("a🇺🇦").collect::<Vec<_>>(), [("a", 1), ("🇺🇦", 2)]);
+    }
+
     #[cfg(feature = "color")]
     #[test]
     fn colored_string_width_test() {
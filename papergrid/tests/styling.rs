@@ -2,7 +2,15 @@
 
 mod util;
 
-use papergrid::config::{AlignmentHorizontal, Border, Borders, Entity, Indent, Sides};
+use std::collections::HashMap;
+
+use papergrid::{
+    color::AnsiColor,
+    config::{AlignmentHorizontal, Border, Borders, Entity, Indent, Sides},
+    dimension::Estimate,
+    grid::spanned::{ExactDimension, Grid, GridConfig},
+    records::IterRecords,
+};
 
 #[cfg(feature = "color")]
 use std::convert::TryFrom;
@@ -342,3 +350,110 @@ fn grid_2x2_ansi_border_none_if_string_is_not_1_char_test() {
     assert!(AnsiColor::try_from("1".on_red().blue().to_string()).is_ok());
     assert!(AnsiColor::try_from("1".truecolor(0, 1, 3).on_truecolor(1, 2, 3).to_string()).is_ok());
 }
+
+test_table!(
+    grid_2x2_background_test,
+    grid(2, 2)
+        .change_cell((0, 0), "0")
+        .config(|cfg| {
+            cfg.set_padding(Entity::Global, Sides::new(Indent::spaced(1), Indent::spaced(1), Indent::default(), Indent::default()));
+            cfg.set_background(Entity::Cell(0, 0), AnsiColor::new("\u{1b}[41m".into(), "\u{1b}[49m".into()));
+        })
+        .build(),
+    "+-----+-----+"
+    "|\u{1b}[41m 0   \u{1b}[49m| 0-1 |"
+    "+-----+-----+"
+    "| 1-0 | 1-1 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    grid_2x2_background_multiline_test,
+    grid(2, 2)
+        .change_cell((0, 1), "0-1\n0-1")
+        .config(|cfg| {
+            cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Right);
+            cfg.set_background(Entity::Column(0), AnsiColor::new("\u{1b}[41m".into(), "\u{1b}[49m".into()));
+        })
+        .build(),
+    "+---+---+"
+    "|\u{1b}[41m0-0\u{1b}[49m|0-1|"
+    "|\u{1b}[41m   \u{1b}[49m|0-1|"
+    "+---+---+"
+    "|\u{1b}[41m1-0\u{1b}[49m|1-1|"
+    "+---+---+"
+);
+
+test_table!(
+    grid_2x2_background_text_color_test,
+    {
+        let data = vec![vec!["0", "0-1"], vec!["1-0", "1-1"]];
+        let data = IterRecords::new(data, 2, Some(2));
+
+        let mut cfg = GridConfig::default();
+        cfg.set_borders(util::DEFAULT_BORDERS);
+        cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Center);
+        cfg.set_background(Entity::Cell(0, 0), AnsiColor::new("\u{1b}[41m".into(), "\u{1b}[49m".into()));
+
+        let mut colors = HashMap::new();
+        colors.insert((0, 0), AnsiColor::new("\u{1b}[34m".into(), "\u{1b}[0m".into()));
+
+        let mut dims = ExactDimension::default();
+        dims.estimate(&data, &cfg);
+
+        Grid::new(&data, &dims, &cfg, colors).to_string()
+    },
+    "+---+---+"
+    "|\u{1b}[41m \u{1b}[34m0\u{1b}[0m\u{1b}[41m \u{1b}[49m|0-1|"
+    "+---+---+"
+    "|1-0|1-1|"
+    "+---+---+"
+);
+
+test_table!(
+    grid_2x2_row_color_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_border_color_global(AnsiColor::new("\u{1b}[32m".into(), "\u{1b}[39m".into()));
+            cfg.set_row_color(1, AnsiColor::new("\u{1b}[44m".into(), "\u{1b}[49m".into()));
+            cfg.set_background(Entity::Cell(1, 1), AnsiColor::new("\u{1b}[41m".into(), "\u{1b}[49m".into()));
+        })
+        .build(),
+    "\u{1b}[32m+---+---+\u{1b}[39m"
+    "\u{1b}[32m|\u{1b}[39m0-0\u{1b}[32m|\u{1b}[39m0-1\u{1b}[32m|\u{1b}[39m"
+    "\u{1b}[32m+---+---+\u{1b}[39m"
+    "\u{1b}[44m\u{1b}[32m|\u{1b}[39m\u{1b}[49m\u{1b}[44m1-0\u{1b}[49m\u{1b}[44m\u{1b}[32m|\u{1b}[39m\u{1b}[49m\u{1b}[41m1-1\u{1b}[49m\u{1b}[44m\u{1b}[32m|\u{1b}[39m\u{1b}[49m"
+    "\u{1b}[32m+---+---+\u{1b}[39m"
+);
+
+test_table!(
+    grid_2x2_row_color_spanned_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_column_span((1, 0), 2);
+            cfg.set_row_color(1, AnsiColor::new("\u{1b}[44m".into(), "\u{1b}[49m".into()));
+        })
+        .build(),
+    "+---+---+"
+    "|0-0|0-1|"
+    "+---+---+"
+    "\u{1b}[44m|\u{1b}[49m\u{1b}[44m1-0    \u{1b}[49m\u{1b}[44m|\u{1b}[49m"
+    "+---+---+"
+);
+
+test_table!(
+    grid_3x2_row_color_row_spanned_test,
+    grid(3, 2)
+        .config(|cfg| {
+            cfg.set_row_span((0, 0), 2);
+            cfg.set_row_color(0, AnsiColor::new("\u{1b}[44m".into(), "\u{1b}[49m".into()));
+        })
+        .build(),
+    "+---+---+"
+    "\u{1b}[44m|\u{1b}[49m\u{1b}[44m0-0\u{1b}[49m\u{1b}[44m|\u{1b}[49m\u{1b}[44m0-1\u{1b}[49m\u{1b}[44m|\u{1b}[49m"
+    "+\u{1b}[44m   \u{1b}[49m+---+"
+    "|\u{1b}[44m   \u{1b}[49m|1-1|"
+    "+---+---+"
+    "|2-0|2-1|"
+    "+---+---+"
+);
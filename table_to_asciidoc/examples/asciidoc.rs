@@ -0,0 +1,20 @@
+//! The example can be run by this command
+//! `cargo run --example asciidoc`
+
+use table_to_asciidoc::{Alignment, AsciiDocTable};
+
+fn main() {
+    let data = vec![
+        ["name", "based_on", "version"],
+        ["Debian", "", "11"],
+        ["Kubuntu", "Ubuntu", "22.10"],
+        ["Xubuntu", "", ""],
+    ];
+
+    let mut table = AsciiDocTable::new(data);
+    table.set_alignment(2, Alignment::right());
+    table.set_column_span((1, 0), 2);
+    table.set_row_span((2, 1), 2);
+
+    println!("{table}")
+}
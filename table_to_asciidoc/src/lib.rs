@@ -0,0 +1,228 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_asciidoc
+//!
+//! The library provides a interface to build an AsciiDoc table (`|===`).
+//!
+//! Column alignment is put into a `cols` attribute,
+//! and spans are put into a cell specifier (`2+|`, `.2+|`).
+//!
+//! ## Example building a table from iterator
+//!
+//! ```rust
+//! use table_to_asciidoc::{AsciiDocTable, Alignment};
+//!
+//! let data = vec![
+//!     vec!["name", "based_on", "version"],
+//!     vec!["Debian", "", "11"],
+//!     vec!["Kubuntu", "Ubuntu", "22.10"],
+//!     vec!["Xubuntu", "", ""],
+//! ];
+//!
+//! let mut table = AsciiDocTable::new(data);
+//! table.set_alignment(2, Alignment::right());
+//! table.set_column_span((1, 0), 2);
+//! table.set_row_span((2, 1), 2);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "[cols=\"<,<,>\",options=\"header\"]\n",
+//!         "|===\n",
+//!         "|name |based_on |version\n",
+//!         "2+|Debian |11\n",
+//!         "|Kubuntu .2+|Ubuntu |22.10\n",
+//!         "|Xubuntu |\n",
+//!         "|===",
+//!     ),
+//! )
+//! ```
+
+use std::{collections::HashMap, fmt, iter::FromIterator};
+
+use tabled::{
+    builder::Builder,
+    grid::config::{AlignmentHorizontal, Position},
+    tables::table::{column_alignments, visible_cells},
+    Table,
+};
+
+pub use tabled::settings::Alignment;
+
+/// The structure represents an AsciiDoc table.
+#[derive(Debug, Clone)]
+pub struct AsciiDocTable {
+    data: Vec<Vec<String>>,
+    alignment: Vec<Option<AlignmentHorizontal>>,
+    column_spans: HashMap<Position, usize>,
+    row_spans: HashMap<Position, usize>,
+    header: bool,
+}
+
+impl AsciiDocTable {
+    /// Creates a new AsciiDoc table from a given elements.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::from(Builder::from_iter(
+            iter.into_iter()
+                .map(|row| row.into_iter().map(|s| s.into())),
+        ))
+    }
+
+    /// Set whether the first row is a header.
+    ///
+    /// It's on by default.
+    pub fn set_header(&mut self, on: bool) {
+        self.header = on;
+    }
+
+    /// Set an alignment for a given column.
+    ///
+    /// Only horizontal alignment is used, so vertical one is ignored.
+    pub fn set_alignment(&mut self, column: usize, alignment: Alignment) {
+        if let Some(alignment) = alignment.get_horizontal() {
+            if column < self.alignment.len() {
+                self.alignment[column] = Some(alignment);
+            }
+        }
+    }
+
+    /// Set a column span for a given cell, which is printed as `n+|`.
+    pub fn set_column_span(&mut self, pos: Position, span: usize) {
+        if span > 1 {
+            self.column_spans.insert(pos, span);
+        } else {
+            self.column_spans.remove(&pos);
+        }
+    }
+
+    /// Set a row span for a given cell, which is printed as `.n+|`.
+    pub fn set_row_span(&mut self, pos: Position, span: usize) {
+        if span > 1 {
+            self.row_spans.insert(pos, span);
+        } else {
+            self.row_spans.remove(&pos);
+        }
+    }
+
+    /// Checks whether a cell is hidden by other cell span.
+    fn is_cell_covered(&self, pos: Position) -> bool {
+        let (row, col) = pos;
+
+        let spans = self.row_spans.keys().chain(self.column_spans.keys());
+        spans.filter(|&&p| p != pos).any(|&p| {
+            let rows = self.row_spans.get(&p).copied().unwrap_or(1);
+            let cols = self.column_spans.get(&p).copied().unwrap_or(1);
+            p.0 <= row && row < p.0 + rows && p.1 <= col && col < p.1 + cols
+        })
+    }
+}
+
+impl From<Builder> for AsciiDocTable {
+    fn from(value: Builder) -> Self {
+        let count_columns = value.count_columns();
+        let data: Vec<Vec<_>> = value.into();
+
+        Self {
+            data,
+            alignment: vec![None; count_columns],
+            column_spans: HashMap::new(),
+            row_spans: HashMap::new(),
+            header: true,
+        }
+    }
+}
+
+impl From<&Table> for AsciiDocTable {
+    /// Creates an AsciiDoc table from a [`Table`].
+    ///
+    /// Alignment and spans are taken from a [`Table`] configuration.
+    fn from(value: &Table) -> Self {
+        let cfg = value.get_config();
+
+        let data = visible_cells(value);
+        let alignment = column_alignments(value);
+
+        Self {
+            data,
+            alignment,
+            column_spans: cfg.get_column_spans(),
+            row_spans: cfg.get_row_spans(),
+            header: true,
+        }
+    }
+}
+
+impl fmt::Display for AsciiDocTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cols = self
+            .alignment
+            .iter()
+            .map(|alignment| match alignment {
+                Some(AlignmentHorizontal::Left) | None => "<",
+                Some(AlignmentHorizontal::Center) => "^",
+                Some(AlignmentHorizontal::Right) => ">",
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        write!(f, "[cols=\"{}\"", cols)?;
+        if self.header && !self.data.is_empty() {
+            f.write_str(",options=\"header\"")?;
+        }
+        f.write_str("]\n|===\n")?;
+
+        for (row, columns) in self.data.iter().enumerate() {
+            let mut is_first = true;
+            for col in 0..self.alignment.len() {
+                if self.is_cell_covered((row, col)) {
+                    continue;
+                }
+
+                if !is_first {
+                    f.write_str(" ")?;
+                }
+                is_first = false;
+
+                let cols = self.column_spans.get(&(row, col)).copied().unwrap_or(1);
+                let rows = self.row_spans.get(&(row, col)).copied().unwrap_or(1);
+                match (cols > 1, rows > 1) {
+                    (true, true) => write!(f, "{}.{}+", cols, rows)?,
+                    (true, false) => write!(f, "{}+", cols)?,
+                    (false, true) => write!(f, ".{}+", rows)?,
+                    (false, false) => {}
+                }
+
+                let text = columns.get(col).map_or("", String::as_str);
+                write!(f, "|{}", escape(text))?;
+            }
+
+            f.write_str("\n")?;
+        }
+
+        f.write_str("|===")
+    }
+}
+
+/// Escapes a cell separator and keeps line breaks.
+fn escape(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .replace('|', "\\|")
+        .replace('\n', " +\n")
+}
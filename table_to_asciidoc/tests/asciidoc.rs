@@ -0,0 +1,113 @@
+use table_to_asciidoc::{Alignment, AsciiDocTable};
+use tabled::{
+    settings::{
+        object::{Cell, Columns},
+        Modify, Span,
+    },
+    Table,
+};
+
+#[test]
+fn asciidoc_table_iter() {
+    let table = AsciiDocTable::new([["123", "324"], ["1", "2"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "[cols=\"<,<\",options=\"header\"]\n",
+            "|===\n",
+            "|123 |324\n",
+            "|1 |2\n",
+            "|===",
+        )
+    );
+}
+
+#[test]
+fn asciidoc_table_empty() {
+    let table = AsciiDocTable::new(Vec::<Vec<String>>::new()).to_string();
+
+    assert_eq!(table, concat!("[cols=\"\"]\n", "|===\n", "|==="));
+}
+
+#[test]
+fn asciidoc_table_no_header_and_alignment() {
+    let mut table = AsciiDocTable::new([["a", "b", "c"]]);
+    table.set_header(false);
+    table.set_alignment(0, Alignment::center());
+    table.set_alignment(1, Alignment::right());
+    table.set_alignment(2, Alignment::top());
+
+    assert_eq!(
+        table.to_string(),
+        concat!("[cols=\"^,>,<\"]\n", "|===\n", "|a |b |c\n", "|===")
+    );
+}
+
+#[test]
+fn asciidoc_table_escaping() {
+    let table = AsciiDocTable::new([["a|b"], ["1\r\n2\n3"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "[cols=\"<\",options=\"header\"]\n",
+            "|===\n",
+            "|a\\|b\n",
+            "|1 +\n",
+            "2 +\n",
+            "3\n",
+            "|===",
+        )
+    );
+}
+
+#[test]
+fn asciidoc_table_spans() {
+    let mut table = AsciiDocTable::new([
+        ["1", "2", "3"],
+        ["4", "5", "6"],
+        ["7", "8", "9"],
+        ["10", "11", "12"],
+    ]);
+    table.set_column_span((1, 0), 2);
+    table.set_row_span((1, 0), 2);
+    table.set_row_span((2, 2), 2);
+    table.set_column_span((3, 0), 1);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "[cols=\"<,<,<\",options=\"header\"]\n",
+            "|===\n",
+            "|1 |2 |3\n",
+            "2.2+|4 |6\n",
+            ".2+|9\n",
+            "|10 |11\n",
+            "|===",
+        )
+    );
+}
+
+#[test]
+fn asciidoc_table_from_table() {
+    let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"]]);
+    table
+        .with(Modify::new(Columns::last()).with(Alignment::right()))
+        .with(Modify::new(Cell::new(0, 0)).with(Span::column(3)))
+        .with(Modify::new(Cell::new(1, 1)).with(Span::row(2)));
+
+    let table = AsciiDocTable::from(&table).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "[cols=\"<,<,>\",options=\"header\"]\n",
+            "|===\n",
+            "3+|0\n",
+            "|1 .2+|2 |3\n",
+            "|4 |6\n",
+            "|===",
+        )
+    );
+}
@@ -0,0 +1,82 @@
+//! The module contains a conversion of ANSI SGR sequences into CSS.
+
+use std::collections::BTreeMap;
+
+use tabled::grid::color::{SgrColor, SgrStyle};
+
+#[cfg(feature = "color")]
+use crate::html::escape;
+
+/// Returns a CSS declarations of the style.
+pub(crate) fn css(style: &SgrStyle) -> BTreeMap<String, String> {
+    let mut m = BTreeMap::new();
+
+    if let Some(fg) = style.fg {
+        m.insert(String::from("color"), css_color(fg));
+    }
+
+    if let Some(bg) = style.bg {
+        m.insert(String::from("background-color"), css_color(bg));
+    }
+
+    if style.bold {
+        m.insert(String::from("font-weight"), String::from("bold"));
+    }
+
+    if style.dim {
+        m.insert(String::from("opacity"), String::from("0.5"));
+    }
+
+    if style.italic {
+        m.insert(String::from("font-style"), String::from("italic"));
+    }
+
+    let decoration = match (style.underline, style.strikethrough) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    };
+
+    if let Some(decoration) = decoration {
+        m.insert(String::from("text-decoration"), String::from(decoration));
+    }
+
+    m
+}
+
+/// Returns a hex representation of a color.
+pub(crate) fn css_color(color: SgrColor) -> String {
+    let (r, g, b) = color.to_rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Converts a text with ANSI SGR sequences into a HTML markup,
+/// where styled parts are wrapped into `<span style="...">`.
+///
+/// The style is passed in so it could be carried on to the next line.
+///
+/// It returns [`None`] in case the text has no escape sequences.
+#[cfg(feature = "color")]
+pub(crate) fn ansi_to_html(text: &str, style: &mut SgrStyle) -> Option<String> {
+    if !text.contains('\u{1b}') && style.is_empty() {
+        return None;
+    }
+
+    let mut buf = String::new();
+    for e in ansitok::parse_ansi(text) {
+        let part = &text[e.start()..e.end()];
+        match e.kind() {
+            // the parameters are in between `ESC[` and `m`
+            ansitok::ElementKind::Sgr => style.apply(&part[2..part.len() - 1]),
+            ansitok::ElementKind::Text if style.is_empty() => buf.push_str(&escape(part)),
+            ansitok::ElementKind::Text => {
+                let css = crate::build_inline_css(&css(style));
+                buf.push_str(&format!("<span style=\"{css}\">{}</span>", escape(part)));
+            }
+            _ => {}
+        }
+    }
+
+    Some(buf)
+}
@@ -48,6 +48,17 @@ impl HtmlElement {
         self.value.as_ref()
     }
 
+    /// Returns a [`Display`] implementation with a given formatting.
+    ///
+    /// A minified output has no indentation and new lines.
+    pub fn display(&self, indent: usize, minified: bool) -> HtmlDisplay<'_> {
+        HtmlDisplay {
+            element: self,
+            indent,
+            minified,
+        }
+    }
+
     /// Visits all desending elements starting from itself.
     pub fn visit<V: HtmlVisitor>(&self, mut visitor: V) {
         self.__visit(&mut visitor);
@@ -78,7 +89,7 @@ impl HtmlElement {
                     }
                 }
             }
-            HtmlValue::Content(_) => {}
+            HtmlValue::Content(_) | HtmlValue::Raw(_) => {}
         }
 
         true
@@ -104,7 +115,7 @@ impl HtmlElement {
                     }
                 }
             }
-            HtmlValue::Content(_) => {}
+            HtmlValue::Content(_) | HtmlValue::Raw(_) => {}
         }
 
         true
@@ -113,7 +124,23 @@ impl HtmlElement {
 
 impl Display for HtmlElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        build_html(f, self)
+        build_html(f, self, 4, false)
+    }
+}
+
+/// A [`Display`] wrapper of a [`HtmlElement`] with a custom formatting.
+///
+/// It's created by [`HtmlElement::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct HtmlDisplay<'a> {
+    element: &'a HtmlElement,
+    indent: usize,
+    minified: bool,
+}
+
+impl Display for HtmlDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        build_html(f, self.element, self.indent, self.minified)
     }
 }
 
@@ -123,7 +150,13 @@ pub enum HtmlValue {
     /// Children elements.
     Elements(Vec<HtmlElement>),
     /// A string content.
+    ///
+    /// It's escaped when printed.
     Content(String),
+    /// A raw HTML content.
+    ///
+    /// It's printed as it is, without escaping.
+    Raw(String),
 }
 
 /// Attribute represents a HTML `key=value` attribute pair.
@@ -183,66 +216,163 @@ where
     }
 }
 
-fn build_html(f: impl std::fmt::Write, e: &HtmlElement) -> std::fmt::Result {
+/// A visitor which makes all string content of visited elements a raw HTML.
+///
+/// It can be used to opt-out of escaping,
+/// in case cells already contain a HTML markup.
+///
+/// ```
+/// use table_to_html::{HtmlTable, html::RawHtml};
+///
+/// let mut table = HtmlTable::new([["<b>bold</b>"]]);
+/// table.visit_mut(RawHtml);
+///
+/// assert!(table.to_string().contains("<b>bold</b>"));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RawHtml;
+
+impl HtmlVisitorMut for RawHtml {
+    fn visit_element_mut(&mut self, e: &mut HtmlElement) -> bool {
+        if let Some(HtmlValue::Content(content)) = &mut e.value {
+            let content = std::mem::take(content);
+            e.value = Some(HtmlValue::Raw(content));
+        }
+
+        true
+    }
+}
+
+/// Escapes HTML special characters in a string.
+pub fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&#39;"),
+            c => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+fn build_html(
+    f: impl std::fmt::Write,
+    e: &HtmlElement,
+    indent: usize,
+    minified: bool,
+) -> std::fmt::Result {
     struct Builder<W> {
         writer: W,
         tab: usize,
+        indent: usize,
+        minified: bool,
         result: std::fmt::Result,
     }
 
     impl<W> Builder<W> {
-        fn new(writer: W, tab: usize) -> Self {
+        fn new(writer: W, indent: usize, minified: bool) -> Self {
             Self {
                 writer,
-                tab,
+                tab: 0,
+                indent,
+                minified,
                 result: Ok(()),
             }
         }
     }
 
-    impl<W: std::fmt::Write> HtmlVisitor for Builder<W> {
-        fn visit_element(&mut self, e: &HtmlElement) -> bool {
-            let space = " ".repeat(self.tab);
+    impl<W: std::fmt::Write> Builder<W> {
+        fn build_minified(&mut self, e: &HtmlElement) -> std::fmt::Result {
+            print_tag(&mut self.writer, e.tag(), e.attrs())?;
 
-            let is_void_element = e.value().is_none();
-            if is_void_element {
-                self.result = write!(self.writer, "{space}");
-                self.result = print_tag(&mut self.writer, e.tag(), e.attrs());
-                return true;
+            let val = match e.value() {
+                Some(val) => val,
+                None => return Ok(()),
+            };
+
+            match val {
+                HtmlValue::Elements(elems) => {
+                    for e in elems {
+                        self.build_minified(e)?;
+                    }
+                }
+                HtmlValue::Content(content) => {
+                    self.writer.write_str(&escape(content))?;
+                }
+                HtmlValue::Raw(content) => {
+                    self.writer.write_str(content)?;
+                }
             }
 
-            if let Some(val) = e.value() {
-                self.result = write!(self.writer, "{space}");
-                self.result = print_tag(&mut self.writer, e.tag(), e.attrs());
-                self.result = writeln!(self.writer);
-
-                match val {
-                    HtmlValue::Elements(elems) => {
-                        self.tab += 4;
-                        for e in elems {
-                            self.visit_element(e);
-                            self.result = writeln!(self.writer);
-                        }
-                        self.tab -= 4;
+            write!(self.writer, "</{}>", e.tag())
+        }
+
+        fn build(&mut self, e: &HtmlElement) -> std::fmt::Result {
+            let space = " ".repeat(self.tab);
+
+            let val = match e.value() {
+                Some(val) => val,
+                None => {
+                    write!(self.writer, "{space}")?;
+                    return print_tag(&mut self.writer, e.tag(), e.attrs());
+                }
+            };
+
+            write!(self.writer, "{space}")?;
+            print_tag(&mut self.writer, e.tag(), e.attrs())?;
+            writeln!(self.writer)?;
+
+            match val {
+                HtmlValue::Elements(elems) => {
+                    self.tab += self.indent;
+                    for e in elems {
+                        self.build(e)?;
+                        writeln!(self.writer)?;
+                    }
+                    self.tab -= self.indent;
+                }
+                HtmlValue::Content(content) => {
+                    let space = " ".repeat(self.tab + self.indent);
+                    for line in get_lines(content) {
+                        write!(self.writer, "{space}")?;
+                        writeln!(self.writer, "{}", escape(&line))?;
                     }
-                    HtmlValue::Content(content) => {
-                        let space = " ".repeat(self.tab + 4);
-                        for line in get_lines(content) {
-                            self.result = write!(self.writer, "{space}");
-                            self.result = writeln!(self.writer, "{line}");
-                        }
+                }
+                HtmlValue::Raw(content) => {
+                    let space = " ".repeat(self.tab + self.indent);
+                    for line in get_lines(content) {
+                        write!(self.writer, "{space}")?;
+                        writeln!(self.writer, "{line}")?;
                     }
                 }
+            }
 
-                self.result = write!(self.writer, "{space}");
-                self.result = write!(self.writer, "</{}>", e.tag());
+            write!(self.writer, "{space}")?;
+            write!(self.writer, "</{}>", e.tag())
+        }
+    }
+
+    impl<W: std::fmt::Write> HtmlVisitor for Builder<W> {
+        fn visit_element(&mut self, e: &HtmlElement) -> bool {
+            // the first error is kept, as the rest of output is meaningless after it
+            if self.result.is_ok() {
+                self.result = if self.minified {
+                    self.build_minified(e)
+                } else {
+                    self.build(e)
+                };
             }
 
             false
         }
     }
 
-    let mut builder = Builder::new(f, 0);
+    let mut builder = Builder::new(f, indent, minified);
     e.visit(&mut builder);
 
     builder.result
@@ -266,7 +396,7 @@ fn print_attributes(mut f: impl std::fmt::Write, attrs: &[Attribute]) -> std::fm
 
         f.write_str(attr.key())?;
         f.write_char('=')?;
-        write!(f, "{:?}", attr.value())?;
+        write!(f, "\"{}\"", escape(attr.value()))?;
     }
 
     Ok(())
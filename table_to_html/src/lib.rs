@@ -152,21 +152,21 @@
 //!         "<table>\n",
 //!         "    <thead>\n",
 //!         "        <tr>\n",
-//!         "            <th>\n",
+//!         "            <th scope=\"col\">\n",
 //!         "                <div>\n",
 //!         "                    <p>\n",
 //!         "                        name\n",
 //!         "                    </p>\n",
 //!         "                </div>\n",
 //!         "            </th>\n",
-//!         "            <th>\n",
+//!         "            <th scope=\"col\">\n",
 //!         "                <div>\n",
 //!         "                    <p>\n",
 //!         "                        based_on\n",
 //!         "                    </p>\n",
 //!         "                </div>\n",
 //!         "            </th>\n",
-//!         "            <th>\n",
+//!         "            <th scope=\"col\">\n",
 //!         "                <div>\n",
 //!         "                    <p>\n",
 //!         "                        is_active\n",
@@ -380,6 +380,8 @@
 
 pub mod html;
 
+mod color;
+
 use std::{
     collections::BTreeMap,
     fmt::{Display, Write},
@@ -388,8 +390,16 @@ use std::{
 
 use html::{HtmlElement, HtmlValue, HtmlVisitor, HtmlVisitorMut};
 use tabled::{
-    builder::Builder,
-    grid::{config::Sides, util::string::get_lines},
+    builder::{Builder, IndexBuilder},
+    grid::{
+        color::{AnsiColor, SgrStyle},
+        config::{AlignmentHorizontal, AlignmentVertical, Sides},
+        spanned::config::ColoredIndent,
+        util::string::get_lines,
+    },
+    records::{ExactRecords, Records},
+    tables::table::ColoredConfig,
+    Table,
 };
 
 use crate::html::Attribute;
@@ -407,6 +417,9 @@ pub use tabled::settings::Alignment;
 pub struct HtmlTable {
     table: HtmlElement,
     css: BTreeMap<String, BTreeMap<String, String>>,
+    indent: usize,
+    minified: bool,
+    cell_wrapper: bool,
 }
 
 impl HtmlTable {
@@ -476,6 +489,118 @@ impl HtmlTable {
         self.css.insert(String::from("table, th, td"), m);
     }
 
+    /// Set a caption for a whole table.
+    ///
+    /// It's rendered as a `<caption>` element,
+    /// the same way [`Panel::header`] puts a title above a [`Table`].
+    ///
+    /// A [`Table`] has no caption in its config,
+    /// so it must be set again after a conversion by `From<&Table>`
+    /// (a [`Panel::header`] is converted into a spanned row, not a caption).
+    ///
+    /// ```
+    /// use table_to_html::HtmlTable;
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Debian", 11)]);
+    ///
+    /// let mut html = HtmlTable::from(&table);
+    /// html.set_caption("Releases");
+    ///
+    /// assert!(html.to_string().contains("<caption>"));
+    /// ```
+    ///
+    /// [`Panel::header`]: tabled::settings::Panel::header
+    /// [`Table`]: tabled::Table
+    pub fn set_caption<S: Into<String>>(&mut self, text: S) {
+        let caption = HtmlElement::new("caption", vec![], Some(HtmlValue::Content(text.into())));
+
+        let mut elements = get_elements(&self.table);
+        elements.retain(|e| e.tag() != "caption");
+        elements.insert(0, caption);
+
+        set_elements(&mut self.table, elements);
+    }
+
+    /// Moves a given number of last rows from a `<tbody>` into a `<tfoot>`.
+    pub fn set_footer(&mut self, count: usize) {
+        let mut elements = get_elements(&self.table);
+
+        let tbody_index = match elements.iter().position(|e| e.tag() == "tbody") {
+            Some(index) => index,
+            None => return,
+        };
+
+        let mut rows = get_elements(&elements[tbody_index]);
+        let mut footer = rows.split_off(rows.len() - count.min(rows.len()));
+        if footer.is_empty() {
+            return;
+        }
+
+        if let Some(index) = elements.iter().position(|e| e.tag() == "tfoot") {
+            footer.extend(get_elements(&elements[index]));
+            elements.remove(index);
+        }
+
+        let tfoot = HtmlElement::new("tfoot", vec![], Some(HtmlValue::Elements(footer)));
+        if rows.is_empty() {
+            elements[tbody_index] = tfoot;
+        } else {
+            set_elements(&mut elements[tbody_index], rows);
+            elements.insert(tbody_index + 1, tfoot);
+        }
+
+        set_elements(&mut self.table, elements);
+    }
+
+    /// Makes a given number of first columns an index.
+    ///
+    /// Cells of the columns in `<tbody>` and `<tfoot>` are rendered as `<th scope="row">`.
+    pub fn set_index(&mut self, count: usize) {
+        let elements = get_elements(&self.table)
+            .into_iter()
+            .map(|mut e| {
+                if e.tag() == "tbody" || e.tag() == "tfoot" {
+                    let rows = get_elements(&e)
+                        .into_iter()
+                        .map(|row| build_row_index(row, count))
+                        .collect();
+
+                    set_elements(&mut e, rows);
+                }
+
+                e
+            })
+            .collect();
+
+        set_elements(&mut self.table, elements);
+    }
+
+    /// Set an indent size used for pretty printing.
+    ///
+    /// CSS declarations inside of a `<style>` element are indented by a half of it.
+    ///
+    /// By default it's 4.
+    pub fn set_indent(&mut self, indent: usize) {
+        self.indent = indent;
+    }
+
+    /// Set whether the output must be minified.
+    ///
+    /// A minified output has no indentation and new lines.
+    pub fn set_minified(&mut self, on: bool) {
+        self.minified = on;
+    }
+
+    /// Set whether a cell content must be wrapped into `<div>` and `<p>` elements.
+    ///
+    /// When turned off lines of a cell are separated by `<br>`.
+    ///
+    /// By default it's on.
+    pub fn set_cell_wrapper(&mut self, on: bool) {
+        self.cell_wrapper = on;
+    }
+
     /// Uses the visitor to traverse a table.
     pub fn visit<V: HtmlVisitor>(&self, visitor: V) {
         self.table.visit(visitor);
@@ -487,6 +612,18 @@ impl HtmlTable {
     }
 }
 
+impl HtmlTable {
+    fn create(table: HtmlElement, css: BTreeMap<String, BTreeMap<String, String>>) -> Self {
+        Self {
+            table,
+            css,
+            indent: 4,
+            minified: false,
+            cell_wrapper: true,
+        }
+    }
+}
+
 impl From<HtmlTable> for HtmlElement {
     fn from(value: HtmlTable) -> Self {
         value.table
@@ -499,22 +636,170 @@ impl From<Builder> for HtmlTable {
         let data: Vec<Vec<_>> = value.into();
         let table = build_table(data, has_header);
 
-        Self {
-            table,
-            css: BTreeMap::default(),
+        Self::create(table, BTreeMap::default())
+    }
+}
+
+impl From<&Table> for HtmlTable {
+    fn from(value: &Table) -> Self {
+        let records = value.get_records();
+        let cfg = value.get_config();
+        let shape = (records.count_rows(), records.count_columns());
+
+        let mut cells = Vec::with_capacity(shape.0);
+        for row in 0..shape.0 {
+            let mut list = Vec::with_capacity(shape.1);
+            for col in 0..shape.1 {
+                let pos = (row, col);
+                if cfg.is_cell_visible(pos) {
+                    list.push((pos, cell_css(cfg, pos, shape)));
+                }
+            }
+
+            cells.push(list);
         }
+
+        let global_css = common_css(
+            &cells
+                .iter()
+                .flatten()
+                .map(|(_, css)| css)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut rows = cells
+            .into_iter()
+            .map(|list| {
+                let cells = list
+                    .into_iter()
+                    .map(|(pos, mut css)| {
+                        css.retain(|key, value| global_css.get(key) != Some(value));
+
+                        let tag = if pos.0 == 0 { "th" } else { "td" };
+
+                        let mut attrs = vec![];
+                        if pos.0 == 0 {
+                            attrs.push(Attribute::new("scope", "col"));
+                        }
+
+                        if let Some(span) = cfg.get_column_span(pos) {
+                            attrs.push(Attribute::new("colspan", span.to_string()));
+                        }
+
+                        if let Some(span) = cfg.get_row_span(pos) {
+                            attrs.push(Attribute::new("rowspan", span.to_string()));
+                        }
+
+                        if !css.is_empty() {
+                            attrs.push(Attribute::new("style", build_inline_css(&css)));
+                        }
+
+                        build_cell(records.get_cell(pos), tag, attrs)
+                    })
+                    .collect();
+
+                HtmlElement::new("tr", vec![], Some(HtmlValue::Elements(cells)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut elements = vec![];
+        if !rows.is_empty() {
+            let header = rows.remove(0);
+            elements.push(HtmlElement::new(
+                "thead",
+                vec![],
+                Some(HtmlValue::Elements(vec![header])),
+            ));
+        }
+
+        elements.push(HtmlElement::new(
+            "tbody",
+            vec![],
+            Some(HtmlValue::Elements(rows)),
+        ));
+
+        let table = HtmlElement::new("table", vec![], Some(HtmlValue::Elements(elements)));
+
+        let mut css = BTreeMap::new();
+        let mut table_css = BTreeMap::new();
+        table_css.insert(String::from("border-collapse"), String::from("collapse"));
+        css.insert(String::from("table"), table_css);
+
+        if !global_css.is_empty() {
+            css.insert(entity_target(Entity::Global), global_css);
+        }
+
+        Self::create(table, css)
+    }
+}
+
+impl From<IndexBuilder> for HtmlTable {
+    fn from(value: IndexBuilder) -> Self {
+        let has_index = value.is_index_visible() || value.is_transposed();
+        let data: Vec<Vec<_>> = Builder::from(value).into();
+        let table = build_table(data, true);
+
+        let mut table = Self::create(table, BTreeMap::default());
+
+        if has_index {
+            table.set_index(1);
+        }
+
+        table
     }
 }
 
 impl Display for HtmlTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if !self.css.is_empty() {
-            let css = build_css(&self.css);
-            css.fmt(f)?;
-            f.write_char('\n')?;
+            let css = build_css(&self.css, self.indent, self.minified);
+            css.display(self.indent, self.minified).fmt(f)?;
+
+            if !self.minified {
+                f.write_char('\n')?;
+            }
         }
 
-        self.table.fmt(f)
+        if self.cell_wrapper {
+            return self.table.display(self.indent, self.minified).fmt(f);
+        }
+
+        let mut table = self.table.clone();
+        table.visit_mut(CellUnwrapper);
+
+        table.display(self.indent, self.minified).fmt(f)
+    }
+}
+
+struct CellUnwrapper;
+
+impl HtmlVisitorMut for CellUnwrapper {
+    fn visit_element_mut(&mut self, e: &mut HtmlElement) -> bool {
+        if e.tag() != "td" && e.tag() != "th" {
+            return true;
+        }
+
+        let lines = get_elements(e)
+            .iter()
+            .flat_map(get_elements)
+            .filter_map(|p| match p.value() {
+                Some(HtmlValue::Content(text)) => Some(html::escape(text)),
+                Some(HtmlValue::Raw(text)) => Some(text.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if lines.is_empty() {
+            return true;
+        }
+
+        *e = HtmlElement::new(
+            e.tag(),
+            e.attrs().to_vec(),
+            Some(HtmlValue::Raw(lines.join("<br>"))),
+        );
+
+        true
     }
 }
 
@@ -596,34 +881,83 @@ fn build_table(mut data: Vec<Vec<String>>, has_header: bool) -> HtmlElement {
 }
 
 fn build_tr(row: Vec<String>) -> HtmlElement {
-    build_row(row, "td")
+    build_row(row, "td", &[])
 }
 
 fn build_th(row: Vec<String>) -> HtmlElement {
-    build_row(row, "th")
+    build_row(row, "th", &[Attribute::new("scope", "col")])
 }
 
-fn build_row(row: Vec<String>, tag: &str) -> HtmlElement {
-    let th_list = row
+fn build_row_index(mut row: HtmlElement, count: usize) -> HtmlElement {
+    let cells = get_elements(&row)
         .into_iter()
-        .map(|content| {
-            HtmlValue::Elements(vec![HtmlElement::new(
-                "div",
-                vec![],
-                Some(HtmlValue::Elements(
-                    get_lines(&content)
-                        .map(|line| HtmlValue::Content(line.to_string()))
-                        .map(|content| HtmlElement::new("p", vec![], Some(content)))
-                        .collect(),
-                )),
-            )])
+        .enumerate()
+        .map(|(i, cell)| {
+            if i >= count || cell.tag() != "td" {
+                return cell;
+            }
+
+            let mut attrs = cell.attrs().to_vec();
+            attrs.insert(0, Attribute::new("scope", "row"));
+
+            HtmlElement::new("th", attrs, cell.value().cloned())
         })
-        .map(|content| HtmlElement::new(tag, vec![], Some(content)))
+        .collect();
+
+    set_elements(&mut row, cells);
+
+    row
+}
+
+fn set_elements(e: &mut HtmlElement, elements: Vec<HtmlElement>) {
+    *e = HtmlElement::new(
+        e.tag(),
+        e.attrs().to_vec(),
+        Some(HtmlValue::Elements(elements)),
+    );
+}
+
+fn get_elements(e: &HtmlElement) -> Vec<HtmlElement> {
+    match e.value() {
+        Some(HtmlValue::Elements(elements)) => elements.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn build_row(row: Vec<String>, tag: &str, attrs: &[Attribute]) -> HtmlElement {
+    let th_list = row
+        .into_iter()
+        .map(|content| build_cell(&content, tag, attrs.to_vec()))
         .collect();
 
     HtmlElement::new("tr", vec![], Some(HtmlValue::Elements(th_list)))
 }
 
+fn build_cell(content: &str, tag: &str, attrs: Vec<Attribute>) -> HtmlElement {
+    #[cfg(feature = "color")]
+    let mut style = SgrStyle::default();
+
+    let content = HtmlValue::Elements(vec![HtmlElement::new(
+        "div",
+        vec![],
+        Some(HtmlValue::Elements(
+            get_lines(content)
+                .map(|line| {
+                    #[cfg(feature = "color")]
+                    if let Some(html) = color::ansi_to_html(&line, &mut style) {
+                        return HtmlValue::Raw(html);
+                    }
+
+                    HtmlValue::Content(line.to_string())
+                })
+                .map(|content| HtmlElement::new("p", vec![], Some(content)))
+                .collect(),
+        )),
+    )]);
+
+    HtmlElement::new(tag, attrs, Some(content))
+}
+
 fn entity_target(target: Entity) -> String {
     match target {
         Entity::Global => String::from("tbody > tr > td, thead > tr > th"),
@@ -687,25 +1021,184 @@ fn alignment_css(val: Alignment) -> BTreeMap<String, String> {
     m
 }
 
-fn build_css(css: &BTreeMap<String, BTreeMap<String, String>>) -> HtmlElement {
+fn cell_css(cfg: &ColoredConfig, pos: Position, shape: (usize, usize)) -> BTreeMap<String, String> {
+    let mut m = BTreeMap::new();
+
+    let (row, col) = pos;
+    let row_span = cfg.get_row_span(pos).unwrap_or(1);
+    let col_span = cfg.get_column_span(pos).unwrap_or(1);
+
+    let borders = [
+        ("border-top", (row, col), true),
+        ("border-bottom", (row + row_span, col), true),
+        ("border-left", (row, col), false),
+        ("border-right", (row, col + col_span), false),
+    ];
+
+    for (key, pos, is_horizontal) in borders {
+        let border = if is_horizontal {
+            cfg.get_horizontal(pos, shape.0)
+                .map(|c| (c, cfg.get_horizontal_color(pos, shape.0)))
+        } else {
+            cfg.get_vertical(pos, shape.1)
+                .map(|c| (c, cfg.get_vertical_color(pos, shape.1)))
+        };
+
+        let css = border
+            .and_then(|(c, color)| border_css(c, color))
+            .unwrap_or_else(|| String::from("none"));
+
+        m.insert(String::from(key), css);
+    }
+
+    let entity = Entity::Cell(row, col);
+
+    let text_align = match cfg.get_alignment_horizontal(entity) {
+        AlignmentHorizontal::Left => "left",
+        AlignmentHorizontal::Right => "right",
+        AlignmentHorizontal::Center => "center",
+    };
+    m.insert(String::from("text-align"), String::from(text_align));
+
+    let vertical_align = match cfg.get_alignment_vertical(entity) {
+        AlignmentVertical::Top => "top",
+        AlignmentVertical::Bottom => "bottom",
+        AlignmentVertical::Center => "middle",
+    };
+    m.insert(String::from("vertical-align"), String::from(vertical_align));
+
+    m.extend(indent_css(cfg.get_padding(entity)));
+
+    if let Some(color) = cfg.get_colors().get(&pos) {
+        m.extend(color::css(&SgrStyle::parse(color.get_prefix())));
+    }
+
+    m
+}
+
+fn border_css(c: char, color: Option<&AnsiColor<'static>>) -> Option<String> {
+    let style = match c {
+        ' ' => return None,
+        '═' | '║' | '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬' => {
+            "3px double"
+        }
+        '━' | '┃' | '┏' | '┓' | '┗' | '┛' | '┣' | '┫' | '┳' | '┻' | '╋' => {
+            "2px solid"
+        }
+        '┄' | '┆' | '┈' | '┊' | '╌' | '╎' => "1px dashed",
+        '.' | ':' | '·' | '┅' | '┇' | '┉' | '┋' => "1px dotted",
+        _ => "1px solid",
+    };
+
+    let color = color
+        .and_then(|color| SgrStyle::parse(color.get_prefix()).fg)
+        .map(color::css_color);
+
+    match color {
+        Some(color) => Some(format!("{style} {color}")),
+        None => Some(String::from(style)),
+    }
+}
+
+fn indent_css(pad: &Sides<ColoredIndent>) -> BTreeMap<String, String> {
+    let size = |size: usize, unit: &str| {
+        if size == 0 {
+            String::from("0")
+        } else {
+            format!("{size}{unit}")
+        }
+    };
+
+    let mut m = BTreeMap::new();
+    m.insert(String::from("padding-top"), size(pad.top.indent.size, "em"));
+    m.insert(
+        String::from("padding-bottom"),
+        size(pad.bottom.indent.size, "em"),
+    );
+    m.insert(
+        String::from("padding-left"),
+        size(pad.left.indent.size, "ch"),
+    );
+    m.insert(
+        String::from("padding-right"),
+        size(pad.right.indent.size, "ch"),
+    );
+
+    m
+}
+
+// Picks the most used value of each property every cell has.
+fn common_css(list: &[&BTreeMap<String, String>]) -> BTreeMap<String, String> {
+    let mut usage: BTreeMap<&String, BTreeMap<&String, usize>> = BTreeMap::new();
+    for css in list {
+        for (key, value) in css.iter() {
+            *usage.entry(key).or_default().entry(value).or_default() += 1;
+        }
+    }
+
+    usage
+        .into_iter()
+        .filter(|(key, _)| list.iter().all(|css| css.contains_key(*key)))
+        .filter_map(|(key, values)| {
+            let max = values.values().copied().max()?;
+            let value = values.into_iter().find(|(_, count)| *count == max)?.0;
+
+            Some((key.clone(), value.clone()))
+        })
+        .collect()
+}
+
+fn build_inline_css(values: &BTreeMap<String, String>) -> String {
+    values
+        .iter()
+        .map(|(key, val)| format!("{key}: {val}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn build_css(
+    css: &BTreeMap<String, BTreeMap<String, String>>,
+    indent: usize,
+    minified: bool,
+) -> HtmlElement {
+    let separator = if minified { "" } else { "\n" };
+
     HtmlElement::new(
         "style",
         vec![],
-        Some(HtmlValue::Content(
+        Some(HtmlValue::Raw(
             css.iter()
-                .map(|(target, style)| build_css_config(target, style))
+                .map(|(target, style)| build_css_config(target, style, indent, minified))
                 .collect::<Vec<_>>()
-                .join("\n"),
+                .join(separator),
         )),
     )
 }
 
-fn build_css_config(target: &str, values: &BTreeMap<String, String>) -> String {
+fn build_css_config(
+    target: &str,
+    values: &BTreeMap<String, String>,
+    indent: usize,
+    minified: bool,
+) -> String {
     let mut buf = String::new();
+
+    if minified {
+        let _ = write!(buf, "{target}{{");
+        let _ = write!(buf, "{}", build_inline_css(values).replace(": ", ":"));
+        let _ = write!(buf, "}}");
+
+        return buf;
+    }
+
+    // declarations are nested by a half of an indent,
+    // so the default indent of 4 keeps CSS indented by 2 spaces as it used to be.
+    let space = " ".repeat(indent / 2);
+
     let _ = writeln!(buf, "{target} {{");
 
     for (key, val) in values {
-        let _ = writeln!(buf, "  {key}: {val};");
+        let _ = writeln!(buf, "{space}{key}: {val};");
     }
 
     let _ = write!(buf, "}}");
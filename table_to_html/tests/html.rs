@@ -139,3 +139,77 @@ fn html_element_visitor_mut() {
         )
     );
 }
+
+#[test]
+fn html_content_escaping() {
+    let table = HtmlElement::new(
+        "td",
+        vec![Attribute::new("title", "\"quoted\"")],
+        Some(HtmlValue::Elements(vec![
+            HtmlElement::new(
+                "p",
+                vec![],
+                Some(HtmlValue::Content(String::from("<a & b>"))),
+            ),
+            HtmlElement::new(
+                "p",
+                vec![],
+                Some(HtmlValue::Raw(String::from("<i>raw</i>"))),
+            ),
+        ])),
+    );
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "<td title=\"&quot;quoted&quot;\">\n",
+            "    <p>\n",
+            "        &lt;a &amp; b&gt;\n",
+            "    </p>\n",
+            "    <p>\n",
+            "        <i>raw</i>\n",
+            "    </p>\n",
+            "</td>"
+        )
+    );
+
+    assert_eq!(
+        table.display(0, true).to_string(),
+        "<td title=\"&quot;quoted&quot;\"><p>&lt;a &amp; b&gt;</p><p><i>raw</i></p></td>"
+    );
+}
+
+#[test]
+fn html_display_keeps_first_error() {
+    use std::fmt::{self, Write};
+
+    // fails only on a first write
+    struct Writer(usize);
+
+    impl Write for Writer {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            self.0 += 1;
+            if self.0 == 1 {
+                Err(fmt::Error)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let table = HtmlElement::new(
+        "tr",
+        vec![],
+        Some(HtmlValue::Elements(vec![HtmlElement::new(
+            "td",
+            vec![],
+            Some(HtmlValue::Content(String::from("1"))),
+        )])),
+    );
+
+    for minified in [true, false] {
+        let mut writer = Writer(0);
+        let result = write!(writer, "{}", table.display(4, minified));
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,12 @@
-use table_to_html::{Alignment, Entity, HtmlTable, Margin, Padding};
-use tabled::Table;
+use table_to_html::{html::RawHtml, Alignment, Entity, HtmlTable, Margin, Padding};
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Cell, Rows},
+        Color, Modify, Span, Style,
+    },
+    Table,
+};
 
 #[test]
 fn table_iter_fmt() {
@@ -77,21 +84,21 @@ fn table_fmt() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -169,21 +176,21 @@ fn table_fmt_multiline() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -296,21 +303,21 @@ fn table_padding_cell() {
                 "<table>\n",
                 "    <thead>\n",
                 "        <tr>\n",
-                "            <th>\n",
+                "            <th scope=\"col\">\n",
                 "                <div>\n",
                 "                    <p>\n",
                 "                        0\n",
                 "                    </p>\n",
                 "                </div>\n",
                 "            </th>\n",
-                "            <th>\n",
+                "            <th scope=\"col\">\n",
                 "                <div>\n",
                 "                    <p>\n",
                 "                        1\n",
                 "                    </p>\n",
                 "                </div>\n",
                 "            </th>\n",
-                "            <th>\n",
+                "            <th scope=\"col\">\n",
                 "                <div>\n",
                 "                    <p>\n",
                 "                        2\n",
@@ -396,21 +403,21 @@ fn table_padding_row() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -496,21 +503,21 @@ fn table_padding_column() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -596,21 +603,21 @@ fn table_padding_global() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -693,21 +700,21 @@ fn table_alignment_left() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -785,21 +792,21 @@ fn table_span_column() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th colspan=\"3\">\n",
+            "            <th scope=\"col\" colspan=\"3\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -877,21 +884,21 @@ fn table_span_row() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -970,21 +977,21 @@ fn table_span_row_and_span_col() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -1069,21 +1076,21 @@ fn table_margin() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -1165,21 +1172,21 @@ fn table_border() {
             "<table>\n",
             "    <thead>\n",
             "        <tr>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        0\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        1\n",
             "                    </p>\n",
             "                </div>\n",
             "            </th>\n",
-            "            <th>\n",
+            "            <th scope=\"col\">\n",
             "                <div>\n",
             "                    <p>\n",
             "                        2\n",
@@ -1240,3 +1247,303 @@ fn table_border() {
         )
     )
 }
+
+#[test]
+fn table_caption_and_footer() {
+    let mut builder = Builder::default().set_header(["name", "value"]);
+    builder.push_record(["a", "1"]);
+    builder.push_record(["total", "1"]);
+
+    let mut table = HtmlTable::from(builder);
+    table.set_caption("Report");
+    table.set_footer(1);
+
+    let table = table.to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "<table>\n",
+            "    <caption>\n",
+            "        Report\n",
+            "    </caption>\n",
+            "    <thead>\n",
+            "        <tr>\n",
+            "            <th scope=\"col\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        name\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "            <th scope=\"col\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        value\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "        </tr>\n",
+            "    </thead>\n",
+            "    <tbody>\n",
+            "        <tr>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        a\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        1\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "        </tr>\n",
+            "    </tbody>\n",
+            "    <tfoot>\n",
+            "        <tr>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        total\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        1\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "        </tr>\n",
+            "    </tfoot>\n",
+            "</table>",
+        )
+    )
+}
+
+#[test]
+fn table_index() {
+    let mut builder = Builder::default().set_header(["name"]);
+    builder.push_record(["a"]);
+
+    let table = HtmlTable::from(builder.index()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "<table>\n",
+            "    <thead>\n",
+            "        <tr>\n",
+            "            <th scope=\"col\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        \n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "            <th scope=\"col\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        name\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "        </tr>\n",
+            "    </thead>\n",
+            "    <tbody>\n",
+            "        <tr>\n",
+            "            <th scope=\"row\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        0\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        a\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "        </tr>\n",
+            "    </tbody>\n",
+            "</table>",
+        )
+    )
+}
+
+#[test]
+fn table_from_table() {
+    let mut table = Table::new([["a", "b"], ["c", "d"]]);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Rows::first()).with(Color::FG_RED))
+        .with(Modify::new(Cell::new(1, 1)).with(Alignment::right()))
+        .with(Modify::new(Cell::new(2, 0)).with(Span::column(2)));
+
+    let table = HtmlTable::from(&table).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "<style>\n",
+            "    table {\n",
+            "      border-collapse: collapse;\n",
+            "    }\n",
+            "    tbody > tr > td, thead > tr > th {\n",
+            "      border-bottom: 1px solid;\n",
+            "      border-left: 1px solid;\n",
+            "      border-right: 1px solid;\n",
+            "      border-top: 1px solid;\n",
+            "      padding-bottom: 0;\n",
+            "      padding-left: 1ch;\n",
+            "      padding-right: 1ch;\n",
+            "      padding-top: 0;\n",
+            "      text-align: left;\n",
+            "      vertical-align: top;\n",
+            "    }\n",
+            "</style>\n",
+            "<table>\n",
+            "    <thead>\n",
+            "        <tr>\n",
+            "            <th scope=\"col\" style=\"color: #800000\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        0\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "            <th scope=\"col\" style=\"color: #800000\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        1\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </th>\n",
+            "        </tr>\n",
+            "    </thead>\n",
+            "    <tbody>\n",
+            "        <tr>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        a\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "            <td style=\"text-align: right\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        b\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "        </tr>\n",
+            "        <tr>\n",
+            "            <td colspan=\"2\">\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        c\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "        </tr>\n",
+            "    </tbody>\n",
+            "</table>",
+        )
+    )
+}
+
+#[test]
+fn table_minified() {
+    let mut table = HtmlTable::new([["<b>1</b>", "a & b\nc"]]);
+    table.set_alignment(Entity::Global, Alignment::left());
+    table.set_minified(true);
+    table.set_cell_wrapper(false);
+
+    let table = table.to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "<style>tbody > tr > td, thead > tr > th{text-align:left}</style>",
+            "<table><tbody><tr>",
+            "<td>&lt;b&gt;1&lt;/b&gt;</td>",
+            "<td>a &amp; b<br>c</td>",
+            "</tr></tbody></table>",
+        )
+    )
+}
+
+#[test]
+fn table_indent_and_raw_html() {
+    let mut table = HtmlTable::new([["<b>1</b>", "2"]]);
+    table.set_indent(2);
+    table.set_cell_wrapper(false);
+    table.visit_mut(RawHtml);
+
+    let table = table.to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "<table>\n",
+            "  <tbody>\n",
+            "    <tr>\n",
+            "      <td>\n",
+            "        <b>1</b>\n",
+            "      </td>\n",
+            "      <td>\n",
+            "        2\n",
+            "      </td>\n",
+            "    </tr>\n",
+            "  </tbody>\n",
+            "</table>",
+        )
+    )
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn table_ansi_colors() {
+    let table = HtmlTable::new([[
+        "\u{1b}[31mred\u{1b}[39m text",
+        "\u{1b}[1;44mbold\nblue\u{1b}[0m <end>",
+    ]])
+    .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "<table>\n",
+            "    <tbody>\n",
+            "        <tr>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        <span style=\"color: #800000\">red</span> text\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "            <td>\n",
+            "                <div>\n",
+            "                    <p>\n",
+            "                        <span style=\"background-color: #000080; font-weight: bold\">bold</span>\n",
+            "                    </p>\n",
+            "                    <p>\n",
+            "                        <span style=\"background-color: #000080; font-weight: bold\">blue</span> &lt;end&gt;\n",
+            "                    </p>\n",
+            "                </div>\n",
+            "            </td>\n",
+            "        </tr>\n",
+            "    </tbody>\n",
+            "</table>",
+        )
+    )
+}
@@ -0,0 +1,19 @@
+//! The example can be run by this command
+//! `cargo run --example latex`
+
+use table_to_latex::{Alignment, LatexTable};
+
+fn main() {
+    let data = vec![
+        ["benchmark", "time_ms", "memory_%"],
+        ["quick_sort", "12.1", "10"],
+        ["merge_sort", "15.4", "25"],
+        ["heap_sort", "17.0", "5"],
+    ];
+
+    let mut table = LatexTable::new(data);
+    table.set_alignment(1, Alignment::right());
+    table.set_alignment(2, Alignment::right());
+
+    println!("{table}")
+}
@@ -0,0 +1,462 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_latex
+//!
+//! The library provides a interface to build a LaTeX `tabular` environment.
+//!
+//! By default rules are printed by [`booktabs`] commands (`\toprule`, `\midrule`, `\bottomrule`),
+//! which can be changed to a plain `\hline` by [`LatexTable::set_booktabs`].
+//!
+//! ## Example building a table from iterator
+//!
+//! ```rust
+//! use table_to_latex::{LatexTable, Alignment};
+//!
+//! let data = vec![
+//!     vec!["name", "time_ms", "memory_%"],
+//!     vec!["quick_sort", "12", "10"],
+//!     vec!["merge_sort", "15", "25"],
+//! ];
+//!
+//! let mut table = LatexTable::new(data);
+//! table.set_alignment(1, Alignment::right());
+//! table.set_alignment(2, Alignment::right());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "\\begin{tabular}{lrr}\n",
+//!         "\\toprule\n",
+//!         "name & time\\_ms & memory\\_\\% \\\\\n",
+//!         "\\midrule\n",
+//!         "quick\\_sort & 12 & 10 \\\\\n",
+//!         "merge\\_sort & 15 & 25 \\\\\n",
+//!         "\\bottomrule\n",
+//!         "\\end{tabular}",
+//!     ),
+//! )
+//! ```
+//!
+//! ## Example building a table from [`Table`].
+//!
+//! Alignment, spans and horizontal lines are read from a [`Table`] configuration.
+//!
+//! ```rust
+//! use table_to_latex::LatexTable;
+//! use tabled::{Table, settings::{Modify, Span, Style, object::Cell}};
+//!
+//! let mut table = Table::new([["a", "b"], ["c", "d"]]);
+//! table
+//!     .with(Style::modern())
+//!     .with(Modify::new(Cell::new(1, 0)).with(Span::column(2)));
+//!
+//! let mut table = LatexTable::from(&table);
+//! table.set_booktabs(false);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "\\begin{tabular}{|l|l|}\n",
+//!         "\\hline\n",
+//!         "0 & 1 \\\\\n",
+//!         "\\hline\n",
+//!         "\\multicolumn{2}{|l|}{a} \\\\\n",
+//!         "\\hline\n",
+//!         "c & d \\\\\n",
+//!         "\\hline\n",
+//!         "\\end{tabular}",
+//!     ),
+//! )
+//! ```
+//!
+//! A `\multirow` command requires the `multirow` package.
+//!
+//! [`booktabs`]: https://ctan.org/pkg/booktabs
+
+use std::{collections::HashMap, fmt, iter::FromIterator};
+
+use tabled::{
+    builder::Builder,
+    grid::config::{AlignmentHorizontal, Position},
+    tables::table::{column_alignments, visible_cells},
+    Table,
+};
+
+pub use tabled::settings::Alignment;
+
+/// The structure represents a LaTeX `tabular` environment.
+///
+/// The first row is considered to be a header.
+#[derive(Debug, Clone)]
+pub struct LatexTable {
+    data: Vec<Vec<String>>,
+    alignment: Vec<Option<AlignmentHorizontal>>,
+    column_spans: HashMap<Position, usize>,
+    row_spans: HashMap<Position, usize>,
+    horizontal_lines: Vec<bool>,
+    vertical_lines: Vec<bool>,
+    booktabs: bool,
+}
+
+impl LatexTable {
+    /// Creates a new LaTeX table from a given elements.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::from(Builder::from_iter(
+            iter.into_iter()
+                .map(|row| row.into_iter().map(|s| s.into())),
+        ))
+    }
+
+    /// Set an alignment for a given column.
+    ///
+    /// Only horizontal alignment is used, so vertical one is ignored.
+    pub fn set_alignment(&mut self, column: usize, alignment: Alignment) {
+        if let Some(alignment) = alignment.get_horizontal() {
+            if column < self.alignment.len() {
+                self.alignment[column] = Some(alignment);
+            }
+        }
+    }
+
+    /// Set a column span for a given cell, which is printed as `\multicolumn`.
+    pub fn set_column_span(&mut self, pos: Position, span: usize) {
+        if span > 1 {
+            self.column_spans.insert(pos, span);
+        } else {
+            self.column_spans.remove(&pos);
+        }
+    }
+
+    /// Set a row span for a given cell, which is printed as `\multirow`.
+    pub fn set_row_span(&mut self, pos: Position, span: usize) {
+        if span > 1 {
+            self.row_spans.insert(pos, span);
+        } else {
+            self.row_spans.remove(&pos);
+        }
+    }
+
+    /// Set whether a horizontal line is printed before a given row.
+    ///
+    /// A row equal to a count of rows represents a bottom line.
+    pub fn set_horizontal_line(&mut self, row: usize, on: bool) {
+        if row < self.horizontal_lines.len() {
+            self.horizontal_lines[row] = on;
+        }
+    }
+
+    /// Set whether a vertical line is printed before a given column.
+    ///
+    /// A column equal to a count of columns represents a right line.
+    ///
+    /// Vertical lines are not printed in [`booktabs`](Self::set_booktabs) mode.
+    pub fn set_vertical_line(&mut self, column: usize, on: bool) {
+        if column < self.vertical_lines.len() {
+            self.vertical_lines[column] = on;
+        }
+    }
+
+    /// Set whether `booktabs` rules are used instead of `\hline` and `\cline`.
+    ///
+    /// It's on by default.
+    pub fn set_booktabs(&mut self, on: bool) {
+        self.booktabs = on;
+    }
+}
+
+impl From<Builder> for LatexTable {
+    fn from(value: Builder) -> Self {
+        let count_columns = value.count_columns();
+        let data: Vec<Vec<_>> = value.into();
+        let count_rows = data.len();
+
+        // top, header and bottom rules
+        let mut horizontal_lines = vec![false; count_rows + 1];
+        horizontal_lines[0] = true;
+        horizontal_lines[count_rows] = true;
+        if count_rows > 1 {
+            horizontal_lines[1] = true;
+        }
+
+        Self {
+            data,
+            alignment: vec![None; count_columns],
+            column_spans: HashMap::new(),
+            row_spans: HashMap::new(),
+            horizontal_lines,
+            vertical_lines: vec![false; count_columns + 1],
+            booktabs: true,
+        }
+    }
+}
+
+impl From<&Table> for LatexTable {
+    /// Creates a LaTeX table from a [`Table`].
+    ///
+    /// [`booktabs`](LatexTable::set_booktabs) rules are used only if a table has no lines between rows of a body,
+    /// as `booktabs` doesn't go along with a grid.
+    /// Otherwise lines are printed as `\hline` the way a table has them.
+    fn from(value: &Table) -> Self {
+        let cfg = value.get_config();
+        let (count_rows, count_columns) = value.shape();
+
+        let data = visible_cells(value);
+        let alignment = column_alignments(value);
+
+        let horizontal_lines = (0..=count_rows)
+            .map(|row| cfg.has_horizontal(row, count_rows))
+            .collect();
+        let vertical_lines = (0..=count_columns)
+            .map(|col| cfg.has_vertical(col, count_columns))
+            .collect();
+
+        let has_body_lines = (2..count_rows).any(|row| cfg.has_horizontal(row, count_rows));
+
+        Self {
+            data,
+            alignment,
+            column_spans: cfg.get_column_spans(),
+            row_spans: cfg.get_row_spans(),
+            horizontal_lines,
+            vertical_lines,
+            booktabs: !has_body_lines,
+        }
+    }
+}
+
+impl fmt::Display for LatexTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count_rows = self.data.len();
+        let count_columns = self.alignment.len();
+
+        if count_rows == 0 || count_columns == 0 {
+            return Ok(());
+        }
+
+        f.write_str("\\begin{tabular}{")?;
+        for col in 0..count_columns {
+            if self.has_vertical(col) {
+                f.write_str("|")?;
+            }
+
+            f.write_str(column_spec(self.alignment[col]))?;
+        }
+        if self.has_vertical(count_columns) {
+            f.write_str("|")?;
+        }
+        f.write_str("}\n")?;
+
+        for row in 0..=count_rows {
+            self.write_horizontal_line(f, row)?;
+
+            if row < count_rows {
+                self.write_row(f, row)?;
+            }
+        }
+
+        f.write_str("\\end{tabular}")
+    }
+}
+
+impl LatexTable {
+    fn has_vertical(&self, col: usize) -> bool {
+        !self.booktabs && self.vertical_lines.get(col).copied().unwrap_or(false)
+    }
+
+    fn write_row(&self, f: &mut fmt::Formatter<'_>, row: usize) -> fmt::Result {
+        let count_columns = self.alignment.len();
+
+        let mut col = 0;
+        while col < count_columns {
+            if col > 0 {
+                f.write_str(" & ")?;
+            }
+
+            if let Some((_, span)) = self.find_row_span_origin((row, col)) {
+                // a cell is covered by a `\multirow` from above,
+                // so an empty cell of the same width is put.
+                if span > 1 {
+                    self.write_multicolumn(f, col, span, "")?;
+                }
+
+                col += span;
+                continue;
+            }
+
+            let text = self.data[row].get(col).map_or("", String::as_str);
+            let mut text = escape(text, self.alignment[col]);
+
+            if let Some(&span) = self.row_spans.get(&(row, col)) {
+                text = format!("\\multirow{{{}}}{{*}}{{{}}}", span, text);
+            }
+
+            let span = self.column_spans.get(&(row, col)).copied().unwrap_or(1);
+            if span > 1 {
+                self.write_multicolumn(f, col, span, &text)?;
+            } else {
+                f.write_str(&text)?;
+            }
+
+            col += span;
+        }
+
+        f.write_str(" \\\\\n")
+    }
+
+    fn write_multicolumn(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        col: usize,
+        span: usize,
+        text: &str,
+    ) -> fmt::Result {
+        let left = if col == 0 && self.has_vertical(0) {
+            "|"
+        } else {
+            ""
+        };
+        let right = if self.has_vertical(col + span) {
+            "|"
+        } else {
+            ""
+        };
+
+        write!(
+            f,
+            "\\multicolumn{{{}}}{{{}{}{}}}{{{}}}",
+            span,
+            left,
+            column_spec(self.alignment[col]),
+            right,
+            text
+        )
+    }
+
+    fn write_horizontal_line(&self, f: &mut fmt::Formatter<'_>, row: usize) -> fmt::Result {
+        let count_rows = self.data.len();
+        let count_columns = self.alignment.len();
+
+        if !self.horizontal_lines.get(row).copied().unwrap_or(false) {
+            return Ok(());
+        }
+
+        if row == 0 || row == count_rows {
+            let rule = match (self.booktabs, row == 0) {
+                (true, true) => "\\toprule",
+                (true, false) => "\\bottomrule",
+                (false, _) => "\\hline",
+            };
+
+            return writeln!(f, "{}", rule);
+        }
+
+        // a line must not cross a `\multirow` cell.
+        let crossed = (0..count_columns)
+            .map(|col| self.find_row_span_origin((row, col)).is_some())
+            .collect::<Vec<_>>();
+
+        if !crossed.contains(&true) {
+            let rule = if self.booktabs {
+                "\\midrule"
+            } else {
+                "\\hline"
+            };
+            return writeln!(f, "{}", rule);
+        }
+
+        let rule = if self.booktabs {
+            "\\cmidrule"
+        } else {
+            "\\cline"
+        };
+
+        let mut rules = Vec::new();
+        let mut col = 0;
+        while col < count_columns {
+            if crossed[col] {
+                col += 1;
+                continue;
+            }
+
+            let start = col;
+            while col < count_columns && !crossed[col] {
+                col += 1;
+            }
+
+            rules.push(format!("{}{{{}-{}}}", rule, start + 1, col));
+        }
+
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "{}", rules.join(" "))
+    }
+
+    /// Looks for a cell in the rows above which spans over a given position.
+    ///
+    /// It returns a position of the cell and its column span.
+    fn find_row_span_origin(&self, pos: Position) -> Option<(Position, usize)> {
+        let (row, col) = pos;
+        self.row_spans
+            .iter()
+            .filter(|&(&(r, _), &span)| r < row && row < r + span)
+            .map(|(&origin, _)| {
+                let span = self.column_spans.get(&origin).copied().unwrap_or(1);
+                (origin, span)
+            })
+            .find(|&((_, c), span)| c <= col && col < c + span)
+    }
+}
+
+fn column_spec(alignment: Option<AlignmentHorizontal>) -> &'static str {
+    match alignment {
+        Some(AlignmentHorizontal::Center) => "c",
+        Some(AlignmentHorizontal::Right) => "r",
+        Some(AlignmentHorizontal::Left) | None => "l",
+    }
+}
+
+/// Escapes LaTeX special characters.
+///
+/// A multiline text is put into a `\shortstack`.
+fn escape(text: &str, alignment: Option<AlignmentHorizontal>) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '~' => buf.push_str("\\textasciitilde{}"),
+            '^' => buf.push_str("\\textasciicircum{}"),
+            '\\' => buf.push_str("\\textbackslash{}"),
+            '\r' => {}
+            '\n' => buf.push_str("\\\\"),
+            c => buf.push(c),
+        }
+    }
+
+    if text.contains('\n') {
+        buf = format!("\\shortstack[{}]{{{}}}", column_spec(alignment), buf);
+    }
+
+    buf
+}
@@ -0,0 +1,188 @@
+use table_to_latex::{Alignment, LatexTable};
+use tabled::{
+    settings::{
+        object::{Cell, Columns},
+        Modify, Span, Style,
+    },
+    Table,
+};
+
+#[test]
+fn latex_table_iter() {
+    let table = LatexTable::new([["123", "324", "zxc"], ["1", "2", "3"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "\\begin{tabular}{lll}\n",
+            "\\toprule\n",
+            "123 & 324 & zxc \\\\\n",
+            "\\midrule\n",
+            "1 & 2 & 3 \\\\\n",
+            "\\bottomrule\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_empty() {
+    let table = LatexTable::new(Vec::<Vec<String>>::new()).to_string();
+
+    assert_eq!(table, "");
+}
+
+#[test]
+fn latex_table_alignment() {
+    let mut table = LatexTable::new([["a", "b", "c", "d"], ["1", "2", "3", "4"]]);
+    table.set_alignment(1, Alignment::center());
+    table.set_alignment(2, Alignment::right());
+    table.set_alignment(3, Alignment::bottom());
+
+    assert!(table.to_string().starts_with("\\begin{tabular}{lcrl}\n"));
+}
+
+#[test]
+fn latex_table_escaping() {
+    let table = LatexTable::new([["#", "text"], ["a & b", "$1 ~ ^2 \\ {x}\n50%"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "\\begin{tabular}{ll}\n",
+            "\\toprule\n",
+            "\\# & text \\\\\n",
+            "\\midrule\n",
+            "a \\& b & \\shortstack[l]{\\$1 \\textasciitilde{} \\textasciicircum{}2 \\textbackslash{} \\{x\\}\\\\50\\%} \\\\\n",
+            "\\bottomrule\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_lines() {
+    let mut table = LatexTable::new([["a", "b"], ["1", "2"], ["3", "4"]]);
+    table.set_booktabs(false);
+    table.set_horizontal_line(2, true);
+    table.set_horizontal_line(3, false);
+    table.set_vertical_line(1, true);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "\\begin{tabular}{l|l}\n",
+            "\\hline\n",
+            "a & b \\\\\n",
+            "\\hline\n",
+            "1 & 2 \\\\\n",
+            "\\hline\n",
+            "3 & 4 \\\\\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_spans() {
+    let mut table = LatexTable::new([
+        ["group", "name", "value"],
+        ["sort", "quick", "1"],
+        ["", "merge", "2"],
+        ["total", "", "3"],
+    ]);
+    table.set_row_span((1, 0), 2);
+    table.set_column_span((3, 0), 2);
+    table.set_horizontal_line(2, true);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "\\begin{tabular}{lll}\n",
+            "\\toprule\n",
+            "group & name & value \\\\\n",
+            "\\midrule\n",
+            "\\multirow{2}{*}{sort} & quick & 1 \\\\\n",
+            "\\cmidrule{2-3}\n",
+            " & merge & 2 \\\\\n",
+            "\\multicolumn{2}{l}{total} & 3 \\\\\n",
+            "\\bottomrule\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_from_table() {
+    let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"], ["7", "8", "9"]]);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Columns::last()).with(Alignment::right()))
+        .with(Modify::new(Cell::new(1, 0)).with(Span::row(2)))
+        .with(Modify::new(Cell::new(1, 0)).with(Span::column(2)));
+
+    let mut table = LatexTable::from(&table);
+    table.set_booktabs(false);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "\\begin{tabular}{|l|l|r|}\n",
+            "\\hline\n",
+            "0 & 1 & 2 \\\\\n",
+            "\\hline\n",
+            "\\multicolumn{2}{|l|}{\\multirow{2}{*}{1}} & 3 \\\\\n",
+            "\\cline{3-3}\n",
+            "\\multicolumn{2}{|l|}{} & 6 \\\\\n",
+            "\\hline\n",
+            "7 & 8 & 9 \\\\\n",
+            "\\hline\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_from_table_grid() {
+    let table = Table::new([["1", "2"], ["3", "4"]]);
+
+    assert_eq!(
+        LatexTable::from(&table).to_string(),
+        concat!(
+            "\\begin{tabular}{|l|l|}\n",
+            "\\hline\n",
+            "0 & 1 \\\\\n",
+            "\\hline\n",
+            "1 & 2 \\\\\n",
+            "\\hline\n",
+            "3 & 4 \\\\\n",
+            "\\hline\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_from_table_booktabs() {
+    let table = Table::new([["1", "2"], ["3", "4"]])
+        .with(Style::psql())
+        .to_owned();
+
+    assert_eq!(
+        LatexTable::from(&table).to_string(),
+        concat!(
+            "\\begin{tabular}{ll}\n",
+            "0 & 1 \\\\\n",
+            "\\midrule\n",
+            "1 & 2 \\\\\n",
+            "3 & 4 \\\\\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_table_from_empty_table() {
+    let table = Table::new(Vec::<[&str; 0]>::new());
+    assert_eq!(LatexTable::from(&table).to_string(), "");
+}
@@ -0,0 +1,18 @@
+//! The example can be run by this command
+//! `cargo run --example markdown`
+
+use table_to_markdown::{Alignment, MarkdownTable};
+
+fn main() {
+    let data = vec![
+        ["name", "based_on", "version"],
+        ["Debian", "", "11"],
+        ["Manjaro", "Arch", "22.0"],
+        ["Ubuntu | Kubuntu", "Debian", "22.10"],
+    ];
+
+    let mut table = MarkdownTable::new(data);
+    table.set_alignment(2, Alignment::right());
+
+    println!("{table}")
+}
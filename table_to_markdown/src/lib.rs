@@ -0,0 +1,232 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_markdown
+//!
+//! The library provides a interface to build a GitHub flavored Markdown table.
+//!
+//! Unlike [`Style::markdown`] it carries a column alignment into a delimiter row (`:---:`),
+//! escapes `|` and replaces new lines with `<br>`, so the table is rendered correctly.
+//!
+//! ## Example building a table from iterator
+//!
+//! ```rust
+//! use table_to_markdown::{MarkdownTable, Alignment};
+//!
+//! let data = vec![
+//!     vec!["name", "based_on", "version"],
+//!     vec!["Debian", "", "11"],
+//!     vec!["Manjaro", "Arch", "22.0"],
+//! ];
+//!
+//! let mut table = MarkdownTable::new(data);
+//! table.set_alignment(2, Alignment::right());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "| name    | based_on | version |\n",
+//!         "| ------- | -------- | ------: |\n",
+//!         "| Debian  |          |      11 |\n",
+//!         "| Manjaro | Arch     |    22.0 |",
+//!     ),
+//! )
+//! ```
+//!
+//! ## Example building a table from [`Table`].
+//!
+//! The alignment is read from a [`Table`] configuration.
+//!
+//! ```rust
+//! use table_to_markdown::MarkdownTable;
+//! use tabled::{Table, settings::{Modify, Alignment, object::Columns}};
+//!
+//! let mut table = Table::new([["Debian", "a | b"], ["Arch", "c\nd"]]);
+//! table.with(Modify::new(Columns::first()).with(Alignment::center()));
+//!
+//! assert_eq!(
+//!     MarkdownTable::from(&table).to_string(),
+//!     concat!(
+//!         "|   0    | 1      |\n",
+//!         "| :----: | ------ |\n",
+//!         "| Debian | a \\| b |\n",
+//!         "|  Arch  | c<br>d |",
+//!     ),
+//! )
+//! ```
+//!
+//! [`Style::markdown`]: tabled::settings::Style::markdown
+
+use std::{fmt, iter::FromIterator};
+
+use tabled::{
+    builder::Builder,
+    grid::{config::AlignmentHorizontal, util::string::string_width},
+    tables::table::{column_alignments, visible_cells},
+    Table,
+};
+
+pub use tabled::settings::Alignment;
+
+/// The structure represents a GitHub flavored Markdown table.
+///
+/// The first row is considered to be a header,
+/// because a Markdown table must have one.
+#[derive(Debug, Clone)]
+pub struct MarkdownTable {
+    data: Vec<Vec<String>>,
+    alignment: Vec<Option<AlignmentHorizontal>>,
+}
+
+impl MarkdownTable {
+    /// Creates a new markdown table from a given elements.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::from(Builder::from_iter(
+            iter.into_iter()
+                .map(|row| row.into_iter().map(|s| s.into())),
+        ))
+    }
+
+    /// Set an alignment for a given column.
+    ///
+    /// Only horizontal alignment is supported by Markdown,
+    /// so vertical one is ignored.
+    pub fn set_alignment(&mut self, column: usize, alignment: Alignment) {
+        if let Some(alignment) = alignment.get_horizontal() {
+            if column < self.alignment.len() {
+                self.alignment[column] = Some(alignment);
+            }
+        }
+    }
+}
+
+impl From<Builder> for MarkdownTable {
+    fn from(value: Builder) -> Self {
+        let count_columns = value.count_columns();
+        let data: Vec<Vec<_>> = value.into();
+
+        Self {
+            data,
+            alignment: vec![None; count_columns],
+        }
+    }
+}
+
+impl From<&Table> for MarkdownTable {
+    /// Creates a markdown table from a [`Table`].
+    ///
+    /// Markdown has no spans, so a spanned cell is flattened,
+    /// meaning its content is kept in the first cell while the covered cells are left empty.
+    fn from(value: &Table) -> Self {
+        let data = visible_cells(value);
+        let alignment = column_alignments(value);
+
+        Self { data, alignment }
+    }
+}
+
+impl fmt::Display for MarkdownTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.data.is_empty() {
+            return Ok(());
+        }
+
+        let data = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|text| escape(text)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let widths = (0..self.alignment.len())
+            .map(|col| {
+                data.iter()
+                    .map(|row| row.get(col).map_or(0, |text| string_width(text)))
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect::<Vec<_>>();
+
+        for (i, row) in data.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+
+            write_row(f, row, &widths, &self.alignment)?;
+
+            if i == 0 {
+                f.write_str("\n")?;
+                write_delimiter_row(f, &widths, &self.alignment)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_row(
+    f: &mut fmt::Formatter<'_>,
+    row: &[String],
+    widths: &[usize],
+    alignment: &[Option<AlignmentHorizontal>],
+) -> fmt::Result {
+    f.write_str("|")?;
+
+    for (col, &width) in widths.iter().enumerate() {
+        let text = row.get(col).map_or("", String::as_str);
+        let available = width - string_width(text);
+        let (left, right) = match alignment[col] {
+            Some(AlignmentHorizontal::Right) => (available, 0),
+            Some(AlignmentHorizontal::Center) => (available / 2, available - available / 2),
+            Some(AlignmentHorizontal::Left) | None => (0, available),
+        };
+
+        write!(f, " {}{}{} |", " ".repeat(left), text, " ".repeat(right))?;
+    }
+
+    Ok(())
+}
+
+fn write_delimiter_row(
+    f: &mut fmt::Formatter<'_>,
+    widths: &[usize],
+    alignment: &[Option<AlignmentHorizontal>],
+) -> fmt::Result {
+    f.write_str("|")?;
+
+    for (&width, alignment) in widths.iter().zip(alignment) {
+        let (left, right) = match alignment {
+            Some(AlignmentHorizontal::Left) => (":", "-"),
+            Some(AlignmentHorizontal::Right) => ("-", ":"),
+            Some(AlignmentHorizontal::Center) => (":", ":"),
+            None => ("-", "-"),
+        };
+
+        write!(f, " {}{}{} |", left, "-".repeat(width - 2), right)?;
+    }
+
+    Ok(())
+}
+
+fn escape(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('\n', "<br>")
+}
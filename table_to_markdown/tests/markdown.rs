@@ -0,0 +1,146 @@
+use table_to_markdown::{Alignment, MarkdownTable};
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Cell, Columns, Rows},
+        Modify, Span,
+    },
+    Table,
+};
+
+#[test]
+fn markdown_table_iter() {
+    let table = MarkdownTable::new([["123", "324", "zxc"], ["1", "2", "3"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| 123 | 324 | zxc |\n",
+            "| --- | --- | --- |\n",
+            "| 1   | 2   | 3   |",
+        )
+    );
+}
+
+#[test]
+fn markdown_table_empty() {
+    let table = MarkdownTable::new(Vec::<Vec<String>>::new()).to_string();
+    assert_eq!(table, "");
+}
+
+#[test]
+fn markdown_table_header_only() {
+    let table = MarkdownTable::new([["a", "b"]]).to_string();
+
+    assert_eq!(table, concat!("| a   | b   |\n", "| --- | --- |"));
+}
+
+#[test]
+fn markdown_table_alignment() {
+    let mut table = MarkdownTable::new([
+        ["left", "center", "right", "none"],
+        ["1", "2", "3", "4"],
+        ["hello", "world", "!", "?"],
+    ]);
+    table.set_alignment(0, Alignment::left());
+    table.set_alignment(1, Alignment::center());
+    table.set_alignment(2, Alignment::right());
+    // vertical alignment is not supported so it's ignored
+    table.set_alignment(3, Alignment::top());
+    // out of bounds column is ignored
+    table.set_alignment(10, Alignment::right());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "| left  | center | right | none |\n",
+            "| :---- | :----: | ----: | ---- |\n",
+            "| 1     |   2    |     3 | 4    |\n",
+            "| hello | world  |     ! | ?    |",
+        )
+    );
+}
+
+#[test]
+fn markdown_table_escaping() {
+    let table = MarkdownTable::new([["a|b", "text"], ["1\r\n2", "3\n4\n5"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| a\\|b   | text        |\n",
+            "| ------ | ----------- |\n",
+            "| 1<br>2 | 3<br>4<br>5 |",
+        )
+    );
+}
+
+#[test]
+fn markdown_table_escaping_backslash() {
+    let table = MarkdownTable::new([["a\\|b", "c:\\dir\\"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!("| a\\\\\\|b | c:\\\\dir\\\\ |\n", "| ------ | --------- |")
+    );
+}
+
+#[test]
+fn markdown_table_from_builder() {
+    let mut builder = Builder::default().set_header(["name", "value"]);
+    builder.push_record(["width", "10"]);
+    builder.push_record(["height"]);
+
+    let table = MarkdownTable::from(builder).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| name   | value |\n",
+            "| ------ | ----- |\n",
+            "| width  | 10    |\n",
+            "| height |       |",
+        )
+    );
+}
+
+#[test]
+fn markdown_table_from_table() {
+    let mut table = Table::new([[1, 2, 3], [4, 5, 6]]);
+    table
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .with(Modify::new(Rows::first()).with(Alignment::center()))
+        .with(Modify::new(Cell::new(1, 2)).with(Alignment::center()));
+
+    let table = MarkdownTable::from(&table).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| 0   |   1 | 2   |\n",
+            "| --- | --: | --- |\n",
+            "| 1   |   2 | 3   |\n",
+            "| 4   |   5 | 6   |",
+        )
+    );
+}
+
+#[test]
+fn markdown_table_from_table_with_span() {
+    let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"]]);
+    table
+        .with(Modify::new(Cell::new(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell::new(0, 2)).with(Span::row(2)));
+
+    let table = MarkdownTable::from(&table).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| 0   | 1   | 2   |\n",
+            "| --- | --- | --- |\n",
+            "| 1   |     |     |\n",
+            "| 4   | 5   | 6   |",
+        )
+    );
+}
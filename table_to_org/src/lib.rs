@@ -0,0 +1,250 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_org
+//!
+//! The library provides a interface to build an Org mode table.
+//!
+//! A header is separated by a `|-` line,
+//! and a column alignment is put into a row of `<l>`, `<c>`, `<r>` cookies.
+//!
+//! ## Example building a table from iterator
+//!
+//! ```rust
+//! use table_to_org::{OrgTable, Alignment};
+//!
+//! let data = vec![
+//!     vec!["name", "based_on", "version"],
+//!     vec!["Debian", "", "11"],
+//!     vec!["Manjaro", "Arch", "22.0"],
+//! ];
+//!
+//! let mut table = OrgTable::new(data);
+//! table.set_alignment(2, Alignment::right());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "| name    | based_on | version |\n",
+//!         "|---------+----------+---------|\n",
+//!         "|         |          |     <r> |\n",
+//!         "| Debian  |          |      11 |\n",
+//!         "| Manjaro | Arch     |    22.0 |",
+//!     ),
+//! )
+//! ```
+
+use std::{fmt, iter::FromIterator};
+
+use tabled::{
+    builder::Builder,
+    grid::{config::AlignmentHorizontal, util::string::string_width},
+    tables::table::{column_alignments, visible_cells},
+    Table,
+};
+
+pub use tabled::settings::Alignment;
+
+/// The structure represents an Org mode table.
+#[derive(Debug, Clone)]
+pub struct OrgTable {
+    data: Vec<Vec<String>>,
+    alignment: Vec<Option<AlignmentHorizontal>>,
+    lines: Vec<bool>,
+}
+
+impl OrgTable {
+    /// Creates a new Org table from a given elements.
+    ///
+    /// The first row is separated from the rest as a header.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::from(Builder::from_iter(
+            iter.into_iter()
+                .map(|row| row.into_iter().map(|s| s.into())),
+        ))
+    }
+
+    /// Set an alignment for a given column.
+    ///
+    /// Only horizontal alignment is used, so vertical one is ignored.
+    pub fn set_alignment(&mut self, column: usize, alignment: Alignment) {
+        if let Some(alignment) = alignment.get_horizontal() {
+            if column < self.alignment.len() {
+                self.alignment[column] = Some(alignment);
+            }
+        }
+    }
+
+    /// Set whether a `|-` separator is printed before a given row.
+    ///
+    /// The first row can't have a separator.
+    pub fn set_separator(&mut self, row: usize, on: bool) {
+        if row > 0 && row < self.lines.len() {
+            self.lines[row] = on;
+        }
+    }
+}
+
+impl From<Builder> for OrgTable {
+    fn from(value: Builder) -> Self {
+        let count_columns = value.count_columns();
+        let data: Vec<Vec<_>> = value.into();
+
+        let mut lines = vec![false; data.len()];
+        if lines.len() > 1 {
+            lines[1] = true;
+        }
+
+        Self {
+            data,
+            alignment: vec![None; count_columns],
+            lines,
+        }
+    }
+}
+
+impl From<&Table> for OrgTable {
+    /// Creates an Org table from a [`Table`].
+    ///
+    /// Org has no spans, so a spanned cell is flattened,
+    /// meaning its content is kept in the first cell while the covered cells are left empty.
+    ///
+    /// Separators are put where a [`Table`] has horizontal lines.
+    fn from(value: &Table) -> Self {
+        let cfg = value.get_config();
+        let count_rows = value.count_rows();
+
+        let data = visible_cells(value);
+        let alignment = column_alignments(value);
+
+        let lines = (0..count_rows)
+            .map(|row| row > 0 && cfg.has_horizontal(row, count_rows))
+            .collect();
+
+        Self {
+            data,
+            alignment,
+            lines,
+        }
+    }
+}
+
+impl fmt::Display for OrgTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|text| escape(text)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let cookies = self.alignment.iter().any(Option::is_some).then(|| {
+            self.alignment
+                .iter()
+                .map(|alignment| match alignment {
+                    Some(AlignmentHorizontal::Left) => String::from("<l>"),
+                    Some(AlignmentHorizontal::Center) => String::from("<c>"),
+                    Some(AlignmentHorizontal::Right) => String::from("<r>"),
+                    None => String::new(),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // a row of alignment cookies goes right after a header.
+        let cookies_row = if self.lines.get(1).copied().unwrap_or(false) {
+            1
+        } else {
+            0
+        };
+
+        let widths = (0..self.alignment.len())
+            .map(|col| {
+                rows.iter()
+                    .chain(cookies.iter())
+                    .map(|row| row.get(col).map_or(0, |text| string_width(text)))
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            })
+            .collect::<Vec<_>>();
+
+        for (row, columns) in rows.iter().enumerate() {
+            if row > 0 {
+                f.write_str("\n")?;
+
+                if self.lines[row] {
+                    write_separator(f, &widths)?;
+                    f.write_str("\n")?;
+                }
+            }
+
+            if row == cookies_row {
+                if let Some(cookies) = &cookies {
+                    write_row(f, cookies, &widths, &self.alignment)?;
+                    f.write_str("\n")?;
+                }
+            }
+
+            write_row(f, columns, &widths, &self.alignment)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_row(
+    f: &mut fmt::Formatter<'_>,
+    row: &[String],
+    widths: &[usize],
+    alignment: &[Option<AlignmentHorizontal>],
+) -> fmt::Result {
+    f.write_str("|")?;
+
+    for (col, &width) in widths.iter().enumerate() {
+        let text = row.get(col).map_or("", String::as_str);
+        let available = width - string_width(text);
+        let (left, right) = match alignment[col] {
+            Some(AlignmentHorizontal::Right) => (available, 0),
+            Some(AlignmentHorizontal::Center) => (available / 2, available - available / 2),
+            Some(AlignmentHorizontal::Left) | None => (0, available),
+        };
+
+        write!(f, " {}{}{} |", " ".repeat(left), text, " ".repeat(right))?;
+    }
+
+    Ok(())
+}
+
+fn write_separator(f: &mut fmt::Formatter<'_>, widths: &[usize]) -> fmt::Result {
+    let line = widths
+        .iter()
+        .map(|width| "-".repeat(width + 2))
+        .collect::<Vec<_>>()
+        .join("+");
+
+    write!(f, "|{}|", line)
+}
+
+/// Escapes a cell separator.
+///
+/// Org tables can't have multiline cells, so new lines are replaced by spaces.
+fn escape(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .replace('|', "\\vert{}")
+        .replace('\n', " ")
+}
@@ -0,0 +1,77 @@
+use table_to_org::{Alignment, OrgTable};
+use tabled::{
+    settings::{
+        object::{Cell, Columns},
+        Modify, Span, Style,
+    },
+    Table,
+};
+
+#[test]
+fn org_table_iter() {
+    let table = OrgTable::new([["123", "324"], ["1", "2"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!("| 123 | 324 |\n", "|-----+-----|\n", "| 1   | 2   |")
+    );
+}
+
+#[test]
+fn org_table_empty() {
+    let table = OrgTable::new(Vec::<Vec<String>>::new()).to_string();
+    assert_eq!(table, "");
+}
+
+#[test]
+fn org_table_separators_and_alignment() {
+    let mut table = OrgTable::new([["a", "b"], ["1", "2"], ["3", "4"]]);
+    table.set_separator(1, false);
+    table.set_separator(2, true);
+    table.set_alignment(0, Alignment::center());
+    table.set_alignment(1, Alignment::left());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "| <c> | <l> |\n",
+            "|  a  | b   |\n",
+            "|  1  | 2   |\n",
+            "|-----+-----|\n",
+            "|  3  | 4   |",
+        )
+    );
+}
+
+#[test]
+fn org_table_escaping() {
+    let table = OrgTable::new([["a|b"], ["1\r\n2\n3"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!("| a\\vert{}b |\n", "|-----------|\n", "| 1 2 3     |",)
+    );
+}
+
+#[test]
+fn org_table_from_table() {
+    let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"]]);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Columns::last()).with(Alignment::right()))
+        .with(Modify::new(Cell::new(1, 0)).with(Span::column(2)));
+
+    let table = OrgTable::from(&table).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| 0 | 1 |   2 |\n",
+            "|---+---+-----|\n",
+            "|   |   | <r> |\n",
+            "| 1 |   |   3 |\n",
+            "|---+---+-----|\n",
+            "| 4 | 5 |   6 |",
+        )
+    );
+}
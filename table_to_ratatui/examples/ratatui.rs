@@ -0,0 +1,34 @@
+//! The example can be run by this command
+//! `cargo run --example ratatui`
+
+use std::iter::FromIterator;
+
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use table_to_ratatui::TableWidget;
+use tabled::{builder::Builder, settings::Style};
+
+fn main() {
+    let data = vec![
+        ["name", "based_on", "is_active"],
+        ["Debian", "", "true"],
+        ["Arch", "", "true"],
+        ["Manjaro", "Arch", "true"],
+    ];
+
+    let table = Builder::from_iter(data)
+        .build()
+        .with(Style::rounded())
+        .to_owned();
+
+    // the area is narrower than the table, so it gets truncated
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 7));
+    TableWidget::new(&table).render(buf.area, &mut buf);
+
+    for y in 0..buf.area.height {
+        let line = (0..buf.area.width)
+            .map(|x| buf[(x, y)].symbol())
+            .collect::<String>();
+
+        println!("{line}");
+    }
+}
@@ -0,0 +1,220 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_ratatui
+//!
+//! The library provides a [`ratatui`] [`Widget`] for a [`Table`].
+//!
+//! A table is rendered by `tabled` itself,
+//! so its styles, spans and computed widths are kept as they are.
+//! Colors (set by [`Color`] or put into cells as ANSI sequences) are converted into [`Style`]s.
+//!
+//! If a table is wider than a widget area it's truncated (or wrapped) by [`Width`] settings,
+//! lines which don't fit into the area are cut off.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+//! use table_to_ratatui::TableWidget;
+//! use tabled::{settings::Style, Table};
+//!
+//! let table = Table::new([("Debian", "11"), ("Manjaro", "22.0")])
+//!     .with(Style::modern())
+//!     .to_owned();
+//!
+//! // the area is narrower than the table so it gets truncated
+//! let mut buf = Buffer::empty(Rect::new(0, 0, 16, 6));
+//! TableWidget::new(&table).render(buf.area, &mut buf);
+//!
+//! assert_eq!(
+//!     buf,
+//!     Buffer::with_lines([
+//!         "┌────────┬─────┐",
+//!         "│ &str   │ &st │",
+//!         "├────────┼─────┤",
+//!         "│ Debian │ 11  │",
+//!         "├────────┼─────┤",
+//!         "│ Manjar │ 22. │",
+//!     ])
+//! );
+//! ```
+//!
+//! [`Widget`]: ratatui::widgets::Widget
+//! [`Style`]: ratatui::style::Style
+//! [`Color`]: tabled::settings::Color
+//! [`Width`]: tabled::settings::Width
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+use tabled::{
+    grid::color::{split_ansi, AnsiPart, SgrColor, SgrStyle},
+    settings::Width,
+    Table,
+};
+
+/// A [`Widget`] which renders a [`Table`].
+///
+/// [`Widget`]: ratatui::widgets::Widget
+#[derive(Debug, Clone, Copy)]
+pub struct TableWidget<'a> {
+    table: &'a Table,
+    style: Style,
+    wrap: bool,
+}
+
+impl<'a> TableWidget<'a> {
+    /// Creates a new [`TableWidget`] structure.
+    pub fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            style: Style::default(),
+            wrap: false,
+        }
+    }
+
+    /// Sets a base style of a table,
+    /// which is patched by colors of the table.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Wrap a table instead of truncating it when it's wider than an area.
+    pub fn wrap(mut self, on: bool) -> Self {
+        self.wrap = on;
+        self
+    }
+}
+
+impl<'a> From<&'a Table> for TableWidget<'a> {
+    fn from(table: &'a Table) -> Self {
+        Self::new(table)
+    }
+}
+
+impl Widget for TableWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let width = usize::from(area.width);
+
+        let mut table = self.table.clone();
+        if table.total_width() > width {
+            if self.wrap {
+                table.with(Width::wrap(width));
+            } else {
+                table.with(Width::truncate(width));
+            }
+        }
+
+        let text = table.to_string();
+        for (line, y) in text.lines().zip(area.top()..area.bottom()) {
+            print_line(buf, line, area.x, y, width, self.style);
+        }
+    }
+}
+
+/// Prints a line splitting it by ANSI sequences, which are converted into styles.
+///
+/// A reset sequence (`ESC[0m`) brings a base style back.
+fn print_line(buf: &mut Buffer, line: &str, x: u16, y: u16, width: usize, base: Style) {
+    let mut sgr = SgrStyle::default();
+    let mut x = x;
+    let mut width = width;
+
+    for part in split_ansi(line) {
+        match part {
+            AnsiPart::Sgr(params) => sgr.apply(params),
+            AnsiPart::Text(text) => {
+                if width == 0 {
+                    break;
+                }
+
+                let style = base.patch(convert_style(&sgr));
+                let (next_x, _) = buf.set_stringn(x, y, text, width, style);
+                width -= usize::from(next_x - x);
+                x = next_x;
+            }
+        }
+    }
+}
+
+/// Converts SGR attributes into a style which patches a base one.
+fn convert_style(sgr: &SgrStyle) -> Style {
+    let mut style = Style::default();
+
+    if let Some(color) = sgr.fg {
+        style = style.fg(convert_color(color));
+    }
+
+    if let Some(color) = sgr.bg {
+        style = style.bg(convert_color(color));
+    }
+
+    let modifiers = [
+        (sgr.bold, Modifier::BOLD),
+        (sgr.dim, Modifier::DIM),
+        (sgr.italic, Modifier::ITALIC),
+        (sgr.underline, Modifier::UNDERLINED),
+        (sgr.blink, Modifier::SLOW_BLINK),
+        (sgr.reversed, Modifier::REVERSED),
+        (sgr.hidden, Modifier::HIDDEN),
+        (sgr.strikethrough, Modifier::CROSSED_OUT),
+    ];
+
+    for (on, modifier) in modifiers.iter() {
+        if *on {
+            style = style.add_modifier(*modifier);
+        }
+    }
+
+    style
+}
+
+fn convert_color(color: SgrColor) -> Color {
+    match color {
+        SgrColor::Basic(n) => basic_color(n),
+        SgrColor::Indexed(n) => Color::Indexed(n),
+        SgrColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+fn basic_color(n: u8) -> Color {
+    match n {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
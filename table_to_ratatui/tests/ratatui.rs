@@ -0,0 +1,230 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color as TuiColor, Modifier, Style as TuiStyle},
+    widgets::Widget,
+};
+use table_to_ratatui::TableWidget;
+use tabled::{
+    settings::{
+        object::{Columns, Rows},
+        Color, Modify, Span, Style,
+    },
+    Table,
+};
+
+fn render(table: &Table, width: u16, height: u16) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+    TableWidget::new(table).render(buf.area, &mut buf);
+    buf
+}
+
+#[test]
+fn ratatui_widget() {
+    let table = Table::new([["123", "324"], ["1", "2"]]);
+
+    assert_eq!(
+        render(&table, 13, 7),
+        Buffer::with_lines([
+            "+-----+-----+",
+            "| 0   | 1   |",
+            "+-----+-----+",
+            "| 123 | 324 |",
+            "+-----+-----+",
+            "| 1   | 2   |",
+            "+-----+-----+",
+        ])
+    );
+}
+
+#[test]
+fn ratatui_widget_bigger_area() {
+    let table = Table::new([["1", "2"]]).with(Style::modern()).to_owned();
+
+    assert_eq!(
+        render(&table, 10, 6),
+        Buffer::with_lines([
+            "┌───┬───┐ ",
+            "│ 0 │ 1 │ ",
+            "├───┼───┤ ",
+            "│ 1 │ 2 │ ",
+            "└───┴───┘ ",
+            "          ",
+        ])
+    );
+}
+
+#[test]
+fn ratatui_widget_offset_area() {
+    let table = Table::new([["1"]]).with(Style::psql()).to_owned();
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+    TableWidget::new(&table).render(Rect::new(1, 1, 4, 3), &mut buf);
+
+    assert_eq!(
+        buf,
+        Buffer::with_lines(["     ", "  0  ", " --- ", "  1  "])
+    );
+}
+
+#[test]
+fn ratatui_widget_span() {
+    let table = Table::new([["123", "324"], ["1", "2"]])
+        .with(Modify::new((0, 0)).with(Span::column(2)))
+        .to_owned();
+
+    assert_eq!(
+        render(&table, 13, 7),
+        Buffer::with_lines([
+            "+-----+-----+",
+            "| 0         |",
+            "+-----+-----+",
+            "| 123 | 324 |",
+            "+-----+-----+",
+            "| 1   | 2   |",
+            "+-----+-----+",
+        ])
+    );
+}
+
+#[test]
+fn ratatui_widget_truncate() {
+    let table = Table::new([["123", "324"], ["1", "2"]]);
+
+    assert_eq!(
+        render(&table, 9, 7),
+        Buffer::with_lines([
+            "+---+---+",
+            "| 0 | 1 |",
+            "+---+---+",
+            "| 1 | 3 |",
+            "+---+---+",
+            "| 1 | 2 |",
+            "+---+---+",
+        ])
+    );
+}
+
+#[test]
+fn ratatui_widget_wrap() {
+    let table = Table::new([["123", "324"], ["1", "2"]]);
+
+    assert_eq!(
+        render_wrapped(&table, 9, 9),
+        Buffer::with_lines([
+            "+---+---+",
+            "| 0 | 1 |",
+            "+---+---+",
+            "| 1 | 3 |",
+            "| 2 | 2 |",
+            "| 3 | 4 |",
+            "+---+---+",
+            "| 1 | 2 |",
+            "+---+---+",
+        ])
+    );
+}
+
+fn render_wrapped(table: &Table, width: u16, height: u16) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+    TableWidget::new(table)
+        .wrap(true)
+        .render(buf.area, &mut buf);
+    buf
+}
+
+#[test]
+fn ratatui_widget_cut_lines() {
+    let table = Table::new([["123", "324"], ["1", "2"]]);
+
+    assert_eq!(
+        render(&table, 13, 4),
+        Buffer::with_lines([
+            "+-----+-----+",
+            "| 0   | 1   |",
+            "+-----+-----+",
+            "| 123 | 324 |",
+        ])
+    );
+}
+
+#[test]
+fn ratatui_widget_color() {
+    let table = Table::new([["1", "2"]])
+        .with(Style::psql())
+        .with(Modify::new(Rows::first()).with(Color::BOLD | Color::FG_RED))
+        .with(Modify::new(Columns::last()).with(Color::rgb_bg(1, 2, 3)))
+        .to_owned();
+
+    let buf = render(&table, 7, 3);
+
+    let mut expected = Buffer::with_lines([" 0 | 1 ", "---+---", " 1 | 2 "]);
+    let red = TuiStyle::default()
+        .fg(TuiColor::Red)
+        .add_modifier(Modifier::BOLD);
+    expected.set_style(Rect::new(1, 0, 1, 1), red);
+    expected.set_style(
+        Rect::new(5, 0, 1, 1),
+        TuiStyle::default().bg(TuiColor::Rgb(1, 2, 3)),
+    );
+    expected.set_style(
+        Rect::new(5, 2, 1, 1),
+        TuiStyle::default().bg(TuiColor::Rgb(1, 2, 3)),
+    );
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn ratatui_widget_base_style() {
+    let table = Table::new([["1"]]).with(Style::blank()).to_owned();
+
+    let style = TuiStyle::default().fg(TuiColor::Blue);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+    TableWidget::new(&table)
+        .style(style)
+        .render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines([" 0 ", " 1 "]);
+    expected.set_style(expected.area, style);
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn ratatui_widget_base_style_after_reset() {
+    let table = Table::new([["1"]])
+        .with(Style::blank())
+        .with(Modify::new(Rows::last()).with(Color::FG_RED))
+        .to_owned();
+
+    let style = TuiStyle::default().fg(TuiColor::Blue);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+    TableWidget::new(&table)
+        .style(style)
+        .render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines([" 0 ", " 1 "]);
+    expected.set_style(expected.area, style);
+    expected.set_style(Rect::new(1, 1, 1, 1), TuiStyle::default().fg(TuiColor::Red));
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn ratatui_widget_ansi_cell_truncate() {
+    let table = Table::new([["\u{1b}[31m12345\u{1b}[39m", "2"]])
+        .with(Style::psql())
+        .to_owned();
+
+    assert_eq!(table.total_width(), 11);
+
+    let buf = render(&table, 10, 3);
+
+    let mut expected = Buffer::with_lines([" 0    | 1 ", "------+---", " 1234 | 2 "]);
+    expected.set_style(Rect::new(1, 2, 4, 1), TuiStyle::default().fg(TuiColor::Red));
+
+    assert_eq!(buf, expected);
+}
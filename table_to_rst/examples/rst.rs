@@ -0,0 +1,21 @@
+//! The example can be run by this command
+//! `cargo run --example rst`
+
+use table_to_rst::RstTable;
+
+fn main() {
+    let data = vec![
+        ["name", "based_on", "version"],
+        ["Debian", "", "11"],
+        ["Kubuntu", "Ubuntu", "22.10"],
+        ["Xubuntu", "", ""],
+    ];
+
+    let mut table = RstTable::new(data);
+    table.set_row_span((2, 1), 2);
+    table.set_column_span((1, 0), 2);
+
+    println!("{table}");
+    println!();
+    println!("{}", table.list_table());
+}
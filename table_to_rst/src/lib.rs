@@ -0,0 +1,467 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_rst
+//!
+//! The library provides a interface to build a reStructuredText table.
+//!
+//! Unlike [`Style::re_structured_text`] which only looks like a simple table,
+//! it builds a grid table which supports row and column spans,
+//! or a `list-table` directive.
+//!
+//! ## Example building a grid table
+//!
+//! ```rust
+//! use table_to_rst::RstTable;
+//!
+//! let data = vec![
+//!     vec!["name", "based_on", "version"],
+//!     vec!["Debian", "", "11"],
+//!     vec!["Kubuntu", "Ubuntu", "22.10"],
+//!     vec!["Xubuntu", "", ""],
+//! ];
+//!
+//! let mut table = RstTable::new(data);
+//! table.set_row_span((2, 1), 2);
+//! table.set_column_span((1, 0), 2);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "+---------+----------+---------+\n",
+//!         "| name    | based_on | version |\n",
+//!         "+=========+==========+=========+\n",
+//!         "| Debian             | 11      |\n",
+//!         "+---------+----------+---------+\n",
+//!         "| Kubuntu | Ubuntu   | 22.10   |\n",
+//!         "+---------+          +---------+\n",
+//!         "| Xubuntu |          |         |\n",
+//!         "+---------+----------+---------+",
+//!     ),
+//! )
+//! ```
+//!
+//! ## Example building a list table
+//!
+//! ```rust
+//! use table_to_rst::RstTable;
+//!
+//! let table = RstTable::new([["name", "version"], ["Debian", "11"]]);
+//!
+//! assert_eq!(
+//!     table.list_table().to_string(),
+//!     concat!(
+//!         ".. list-table::\n",
+//!         "   :header-rows: 1\n",
+//!         "\n",
+//!         "   * - name\n",
+//!         "     - version\n",
+//!         "   * - Debian\n",
+//!         "     - 11",
+//!     ),
+//! )
+//! ```
+//!
+//! [`Style::re_structured_text`]: tabled::settings::Style::re_structured_text
+
+use std::{collections::HashMap, fmt, iter::FromIterator};
+
+use tabled::{
+    builder::Builder,
+    grid::{
+        config::Position,
+        util::string::{get_lines, string_width, string_width_multiline},
+    },
+    records::{ExactRecords, Records},
+    Table,
+};
+
+/// The structure represents a reStructuredText table.
+///
+/// It's printed as a grid table,
+/// and can be printed as a `list-table` directive by [`RstTable::list_table`].
+#[derive(Debug, Clone)]
+pub struct RstTable {
+    data: Vec<Vec<String>>,
+    count_columns: usize,
+    column_spans: HashMap<Position, usize>,
+    row_spans: HashMap<Position, usize>,
+    header: bool,
+}
+
+impl RstTable {
+    /// Creates a new reStructuredText table from a given elements.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::from(Builder::from_iter(
+            iter.into_iter()
+                .map(|row| row.into_iter().map(|s| s.into())),
+        ))
+    }
+
+    /// Set whether the first row is a header.
+    ///
+    /// It's on by default.
+    pub fn set_header(&mut self, on: bool) {
+        self.header = on;
+    }
+
+    /// Set a column span for a given cell.
+    ///
+    /// The cells which are covered by the span are ignored.
+    pub fn set_column_span(&mut self, pos: Position, span: usize) {
+        if span > 1 {
+            self.column_spans.insert(pos, span);
+        } else {
+            self.column_spans.remove(&pos);
+        }
+    }
+
+    /// Set a row span for a given cell.
+    ///
+    /// The cells which are covered by the span are ignored.
+    pub fn set_row_span(&mut self, pos: Position, span: usize) {
+        if span > 1 {
+            self.row_spans.insert(pos, span);
+        } else {
+            self.row_spans.remove(&pos);
+        }
+    }
+
+    /// Returns a [`Display`] implementation which prints a `list-table` directive.
+    ///
+    /// A `list-table` has no spans, so a spanned cell is flattened,
+    /// meaning its content is kept in the first cell while the covered cells are left empty.
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn list_table(&self) -> RstListTable<'_> {
+        RstListTable { table: self }
+    }
+
+    fn get_span(&self, pos: Position) -> (usize, usize) {
+        let (row, col) = pos;
+        let count_rows = self.data.len();
+
+        let rows = self.row_spans.get(&pos).copied().unwrap_or(1);
+        let cols = self.column_spans.get(&pos).copied().unwrap_or(1);
+
+        (
+            rows.min(count_rows - row),
+            cols.min(self.count_columns - col),
+        )
+    }
+
+    /// Checks whether a cell is hidden by other cell span.
+    fn is_cell_covered(&self, pos: Position) -> bool {
+        let (row, col) = pos;
+
+        let spans = self.row_spans.keys().chain(self.column_spans.keys());
+        spans.filter(|&&p| p != pos).any(|&(r, c)| {
+            let (rows, cols) = self.get_span((r, c));
+            r <= row && row < r + rows && c <= col && col < c + cols
+        })
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        self.data[pos.0].get(pos.1).map_or("", String::as_str)
+    }
+}
+
+impl From<Builder> for RstTable {
+    fn from(value: Builder) -> Self {
+        let count_columns = value.count_columns();
+        let data: Vec<Vec<_>> = value.into();
+
+        Self {
+            data,
+            count_columns,
+            column_spans: HashMap::new(),
+            row_spans: HashMap::new(),
+            header: true,
+        }
+    }
+}
+
+impl From<&Table> for RstTable {
+    /// Creates a reStructuredText table from a [`Table`].
+    ///
+    /// Spans are taken from a [`Table`] configuration.
+    fn from(value: &Table) -> Self {
+        let records = value.get_records();
+        let cfg = value.get_config();
+        let (count_rows, count_columns) = (records.count_rows(), records.count_columns());
+
+        let data = (0..count_rows)
+            .map(|row| {
+                (0..count_columns)
+                    .map(|col| records.get_cell((row, col)).clone())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            data,
+            count_columns,
+            column_spans: cfg.get_column_spans(),
+            row_spans: cfg.get_row_spans(),
+            header: true,
+        }
+    }
+}
+
+impl fmt::Display for RstTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count_rows = self.data.len();
+        let count_columns = self.count_columns;
+        if count_rows == 0 || count_columns == 0 {
+            return Ok(());
+        }
+
+        let cells = (0..count_rows)
+            .flat_map(|row| (0..count_columns).map(move |col| (row, col)))
+            .filter(|&pos| !self.is_cell_covered(pos))
+            .collect::<Vec<_>>();
+
+        let (widths, heights) = self.build_dimension(&cells);
+
+        let xs = build_positions(&widths);
+        let ys = build_positions(&heights);
+
+        let mut canvas = Canvas::new(xs[count_columns] + 1, ys[count_rows] + 1);
+
+        for &pos in &cells {
+            let (row, col) = pos;
+            let (rows, cols) = self.get_span(pos);
+
+            let (x1, x2) = (xs[col], xs[col + cols]);
+            let (y1, y2) = (ys[row], ys[row + rows]);
+
+            let is_header_line = |y: usize| self.header && count_rows > 1 && y == ys[1];
+
+            for &y in &[y1, y2] {
+                let c = if is_header_line(y) { '=' } else { '-' };
+                canvas.draw_horizontal(y, x1, x2, c);
+            }
+
+            for &x in &[x1, x2] {
+                canvas.draw_vertical(x, y1, y2);
+            }
+
+            for (i, line) in get_lines(self.get_text(pos)).enumerate() {
+                canvas.write(x1 + 2, y1 + 1 + i, &line);
+            }
+        }
+
+        canvas.fmt(f)
+    }
+}
+
+impl RstTable {
+    /// Calculates widths of columns and heights of rows.
+    ///
+    /// The widths includes a padding around a text.
+    fn build_dimension(&self, cells: &[Position]) -> (Vec<usize>, Vec<usize>) {
+        let mut widths = vec![3; self.count_columns];
+        let mut heights = vec![1; self.data.len()];
+
+        let mut spanned = Vec::new();
+        for &pos in cells {
+            let (row, col) = pos;
+            let text = self.get_text(pos);
+            let width = string_width_multiline(text) + 2;
+            let height = get_lines(text).count().max(1);
+
+            let (rows, cols) = self.get_span(pos);
+            if rows > 1 || cols > 1 {
+                spanned.push((pos, width, height));
+            }
+
+            if cols == 1 {
+                widths[col] = widths[col].max(width);
+            }
+
+            if rows == 1 {
+                heights[row] = heights[row].max(height);
+            }
+        }
+
+        // a spanned cell may need more space than the cells it covers give,
+        // in which case the last column (or row) is enlarged.
+        for ((row, col), width, height) in spanned {
+            let (rows, cols) = self.get_span((row, col));
+
+            let available = widths[col..col + cols].iter().sum::<usize>() + cols - 1;
+            if width > available {
+                widths[col + cols - 1] += width - available;
+            }
+
+            let available = heights[row..row + rows].iter().sum::<usize>() + rows - 1;
+            if height > available {
+                heights[row + rows - 1] += height - available;
+            }
+        }
+
+        (widths, heights)
+    }
+}
+
+/// The structure represents a `list-table` directive.
+///
+/// It's created by [`RstTable::list_table`].
+#[derive(Debug, Clone, Copy)]
+pub struct RstListTable<'a> {
+    table: &'a RstTable,
+}
+
+impl fmt::Display for RstListTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let table = self.table;
+
+        f.write_str(".. list-table::")?;
+        if table.header && !table.data.is_empty() {
+            f.write_str("\n   :header-rows: 1")?;
+        }
+
+        for row in 0..table.data.len() {
+            if row == 0 {
+                f.write_str("\n")?;
+            }
+
+            for col in 0..table.count_columns {
+                let text = if table.is_cell_covered((row, col)) {
+                    ""
+                } else {
+                    table.get_text((row, col))
+                };
+
+                let prefix = if col == 0 { "   * -" } else { "     -" };
+                f.write_str("\n")?;
+                f.write_str(prefix)?;
+
+                for (i, line) in get_lines(text).enumerate() {
+                    if i > 0 {
+                        f.write_str("\n")?;
+                        if !line.is_empty() {
+                            f.write_str("       ")?;
+                        }
+                    } else if !line.is_empty() {
+                        f.write_str(" ")?;
+                    }
+
+                    f.write_str(&line)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A character canvas which a grid table is drawn on.
+///
+/// Each cell holds a string, so combining characters could be kept.
+struct Canvas {
+    width: usize,
+    buf: Vec<Vec<String>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            buf: vec![vec![String::from(" "); width]; height],
+        }
+    }
+
+    fn draw_horizontal(&mut self, y: usize, x1: usize, x2: usize, c: char) {
+        for x in x1 + 1..x2 {
+            self.set_line(x, y, c);
+        }
+
+        self.buf[y][x1] = String::from("+");
+        self.buf[y][x2] = String::from("+");
+    }
+
+    fn draw_vertical(&mut self, x: usize, y1: usize, y2: usize) {
+        for y in y1 + 1..y2 {
+            self.set_line(x, y, '|');
+        }
+    }
+
+    /// Sets a line character unless it's an intersection.
+    fn set_line(&mut self, x: usize, y: usize, c: char) {
+        if self.buf[y][x] != "+" {
+            self.buf[y][x] = c.to_string();
+        }
+    }
+
+    fn write(&mut self, x: usize, y: usize, text: &str) {
+        let mut x = x;
+        for c in text.chars() {
+            let width = string_width(c.encode_utf8(&mut [0; 4]));
+            if width == 0 {
+                // combining characters are appended to a previous one.
+                if let Some(cell) = x.checked_sub(1).and_then(|x| self.buf[y].get_mut(x)) {
+                    cell.push(c);
+                }
+
+                continue;
+            }
+
+            if x + width > self.width {
+                break;
+            }
+
+            self.buf[y][x] = c.to_string();
+            // a wide character takes more than one cell,
+            // so the next cells are left empty.
+            for i in 1..width {
+                self.buf[y][x + i] = String::new();
+            }
+
+            x += width;
+        }
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, line) in self.buf.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+
+            let line = line.concat();
+            f.write_str(line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Calculates positions of lines, where the first one is at 0.
+fn build_positions(sizes: &[usize]) -> Vec<usize> {
+    let mut positions = Vec::with_capacity(sizes.len() + 1);
+
+    let mut pos = 0;
+    positions.push(pos);
+    for size in sizes {
+        pos += size + 1;
+        positions.push(pos);
+    }
+
+    positions
+}
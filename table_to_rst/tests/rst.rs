@@ -0,0 +1,159 @@
+use table_to_rst::RstTable;
+use tabled::{
+    settings::{object::Cell, Modify, Span},
+    Table,
+};
+
+#[test]
+fn rst_table_iter() {
+    let table = RstTable::new([["123", "324"], ["1", "2"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+-----+\n",
+            "| 123 | 324 |\n",
+            "+=====+=====+\n",
+            "| 1   | 2   |\n",
+            "+-----+-----+",
+        )
+    );
+}
+
+#[test]
+fn rst_table_empty() {
+    let table = RstTable::new(Vec::<Vec<String>>::new());
+
+    assert_eq!(table.to_string(), "");
+    assert_eq!(table.list_table().to_string(), ".. list-table::");
+}
+
+#[test]
+fn rst_table_no_header() {
+    let mut table = RstTable::new([["a", "b"], ["c", "d"]]);
+    table.set_header(false);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+---+---+\n",
+            "| a | b |\n",
+            "+---+---+\n",
+            "| c | d |\n",
+            "+---+---+",
+        )
+    );
+    assert_eq!(
+        table.list_table().to_string(),
+        concat!(
+            ".. list-table::\n",
+            "\n",
+            "   * - a\n",
+            "     - b\n",
+            "   * - c\n",
+            "     - d",
+        )
+    );
+}
+
+#[test]
+fn rst_table_multiline() {
+    let table = RstTable::new([["name", "description"], ["tabled", "a table\n\nlibrary"]]);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+--------+-------------+\n",
+            "| name   | description |\n",
+            "+========+=============+\n",
+            "| tabled | a table     |\n",
+            "|        |             |\n",
+            "|        | library     |\n",
+            "+--------+-------------+",
+        )
+    );
+    assert_eq!(
+        table.list_table().to_string(),
+        concat!(
+            ".. list-table::\n",
+            "   :header-rows: 1\n",
+            "\n",
+            "   * - name\n",
+            "     - description\n",
+            "   * - tabled\n",
+            "     - a table\n",
+            "\n",
+            "       library",
+        )
+    );
+}
+
+#[test]
+fn rst_table_span_enlarges_columns() {
+    let mut table = RstTable::new([["a", "b", "c"], ["a long text", "", "1"], ["2", "3", "4"]]);
+    table.set_column_span((1, 0), 2);
+    table.set_row_span((1, 2), 2);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+---+---------+---+\n",
+            "| a | b       | c |\n",
+            "+===+=========+===+\n",
+            "| a long text | 1 |\n",
+            "+---+---------+   |\n",
+            "| 2 | 3       |   |\n",
+            "+---+---------+---+",
+        )
+    );
+    assert_eq!(
+        table.list_table().to_string(),
+        concat!(
+            ".. list-table::\n",
+            "   :header-rows: 1\n",
+            "\n",
+            "   * - a\n",
+            "     - b\n",
+            "     - c\n",
+            "   * - a long text\n",
+            "     -\n",
+            "     - 1\n",
+            "   * - 2\n",
+            "     - 3\n",
+            "     -",
+        )
+    );
+}
+
+#[test]
+fn rst_table_wide_chars() {
+    let table = RstTable::new([["名前", "e\u{301}"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!("+------+---+\n", "| 名前 | e\u{301} |\n", "+------+---+")
+    );
+}
+
+#[test]
+fn rst_table_from_table() {
+    let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"]]);
+    table
+        .with(Modify::new(Cell::new(0, 0)).with(Span::column(3)))
+        .with(Modify::new(Cell::new(1, 0)).with(Span::row(2)));
+
+    let table = RstTable::from(&table).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----------+\n",
+            "| 0         |\n",
+            "+===+===+===+\n",
+            "| 1 | 2 | 3 |\n",
+            "|   +---+---+\n",
+            "|   | 5 | 6 |\n",
+            "+---+---+---+",
+        )
+    );
+}
@@ -0,0 +1,28 @@
+//! The example can be run by this command
+//! `cargo run --example svg > table.svg`
+
+use table_to_svg::SvgTable;
+use tabled::{
+    settings::{object::Rows, Color, Modify, Style},
+    Table,
+};
+
+fn main() {
+    #[rustfmt::skip]
+    let data = vec![
+        ["benchmark", "time_ms", "memory_%"],
+        ["quick_sort", "12.1", "10"],
+        ["merge_sort", "15.4", "25"],
+        ["heap_sort", "17.0", "5"],
+    ];
+
+    let mut table = data.into_iter().collect::<Table>();
+    table
+        .with(Style::modern())
+        .with(Modify::new(Rows::first()).with(Color::FG_BLUE));
+
+    let mut svg = SvgTable::from(&table);
+    svg.set_background(Some("#ffffff"));
+
+    println!("{svg}")
+}
@@ -0,0 +1,708 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_svg
+//!
+//! The library provides a interface to render a [`Table`] as an SVG image.
+//!
+//! A layout is built on the same dimensions the [`Table`] is printed with,
+//! so each character of a terminal output becomes a fixed size box of a monospace font.
+//! Borders are drawn as lines, taking their style from the border characters,
+//! and ANSI colors become fill and stroke colors.
+//!
+//! The result is a self-contained SVG document.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use table_to_svg::SvgTable;
+//! use tabled::{Table, settings::Style};
+//!
+//! let mut table = Table::new([[1, 2]]);
+//! table.with(Style::modern());
+//!
+//! let svg = SvgTable::from(&table).to_string();
+//!
+//! assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+//! assert!(svg.contains(">0</text>"));
+//! assert!(svg.ends_with("</svg>"));
+//! ```
+
+use std::{fmt, iter::FromIterator};
+
+use tabled::{
+    builder::Builder,
+    grid::{
+        color::{split_ansi, strip_ansi, AnsiPart, SgrColor, SgrStyle},
+        config::{AlignmentHorizontal, AlignmentVertical, Entity, Position},
+        dimension::{Dimension, Estimate},
+        spanned::GridConfig,
+        util::string::{count_lines, get_lines, string_width, string_width_multiline},
+    },
+    records::{ExactRecords, Records, VecRecords},
+    tables::table::ColoredConfig,
+    Table,
+};
+
+/// A width of a monospace character relative to a font size.
+const CHAR_WIDTH: f64 = 0.6;
+/// A height of a line relative to a font size.
+const LINE_HEIGHT: f64 = 1.2;
+
+/// The structure represents an SVG image of a table.
+#[derive(Debug, Clone)]
+pub struct SvgTable {
+    width: usize,
+    height: usize,
+    rects: Vec<Rect>,
+    lines: Vec<Line>,
+    texts: Vec<Text>,
+    font_family: String,
+    font_size: usize,
+    foreground: String,
+    background: Option<String>,
+}
+
+impl SvgTable {
+    /// Creates a new SVG table from a given elements.
+    ///
+    /// It's rendered with a default [`Table`] style.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let table = Builder::from_iter(
+            iter.into_iter()
+                .map(|row| row.into_iter().map(|s| s.into())),
+        )
+        .build();
+
+        Self::from(&table)
+    }
+
+    /// Set a font family.
+    ///
+    /// It's supposed to be a monospace font, otherwise the layout would be broken.
+    ///
+    /// Default is `monospace`.
+    pub fn set_font_family<S: Into<String>>(&mut self, family: S) {
+        self.font_family = family.into();
+    }
+
+    /// Set a font size in pixels.
+    ///
+    /// Default is `14`.
+    pub fn set_font_size(&mut self, size: usize) {
+        self.font_size = size;
+    }
+
+    /// Set a default color of a text and borders.
+    ///
+    /// Default is `#000000`.
+    pub fn set_foreground<S: Into<String>>(&mut self, color: S) {
+        self.foreground = color.into();
+    }
+
+    /// Set a background color of an image.
+    ///
+    /// Default is [`None`], which means it's transparent.
+    pub fn set_background<S: Into<String>>(&mut self, color: Option<S>) {
+        self.background = color.map(Into::into);
+    }
+}
+
+impl From<&Table> for SvgTable {
+    fn from(value: &Table) -> Self {
+        let records = value.get_records();
+        let cfg = value.get_config();
+
+        // ANSI sequences are not printable so they must not affect the layout.
+        let stripped = (0..records.count_rows())
+            .map(|row| {
+                (0..records.count_columns())
+                    .map(|col| strip_ansi(records.get_cell((row, col))))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let stripped = VecRecords::new(stripped);
+
+        let mut dimension = value.get_dimension().clone();
+        dimension.estimate(&stripped, cfg);
+
+        let widths = (0..stripped.count_columns())
+            .map(|col| dimension.get_width(col))
+            .collect();
+        let heights = (0..stripped.count_rows())
+            .map(|row| dimension.get_height(row))
+            .collect();
+
+        let layout = Layout::new(cfg, widths, heights);
+
+        let mut rects = Vec::new();
+        let mut texts = Vec::new();
+        for row in 0..layout.heights.len() {
+            for col in 0..layout.widths.len() {
+                if cfg.is_cell_visible((row, col)) {
+                    let text = records.get_cell((row, col));
+                    build_cell(cfg, &layout, (row, col), text, &mut rects, &mut texts);
+                }
+            }
+        }
+
+        let lines = build_lines(cfg, &layout);
+
+        Self {
+            width: layout.width,
+            height: layout.height,
+            rects,
+            lines,
+            texts,
+            font_family: String::from("monospace"),
+            font_size: 14,
+            foreground: String::from("#000000"),
+            background: None,
+        }
+    }
+}
+
+impl fmt::Display for SvgTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let char_width = self.font_size as f64 * CHAR_WIDTH;
+        let line_height = self.font_size as f64 * LINE_HEIGHT;
+
+        let width = num(self.width as f64 * char_width);
+        let height = num(self.height as f64 * line_height);
+
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"{}\" font-size=\"{}\" xml:space=\"preserve\">",
+            escape(&self.font_family),
+            self.font_size,
+            w = width,
+            h = height,
+        )?;
+
+        if let Some(background) = &self.background {
+            writeln!(
+                f,
+                "    <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+                escape(background)
+            )?;
+        }
+
+        for rect in &self.rects {
+            let Rect {
+                x,
+                y,
+                width: w,
+                height: h,
+                color,
+            } = rect;
+
+            writeln!(
+                f,
+                "    <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                num(*x as f64 * char_width),
+                num(*y as f64 * line_height),
+                num(*w as f64 * char_width),
+                num(*h as f64 * line_height),
+                escape(color),
+            )?;
+        }
+
+        for line in &self.lines {
+            let Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                c,
+                color,
+            } = line;
+            let color = color.as_deref().unwrap_or(&self.foreground);
+
+            let (x1, x2) = (x1 * char_width, x2 * char_width);
+            let (y1, y2) = (y1 * line_height, y2 * line_height);
+
+            let (stroke, extra) = match *c {
+                '═' | '║' | '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬' =>
+                {
+                    // a double line is drawn as 2 parallel lines.
+                    let (dx, dy) = if y1 == y2 { (0.0, 1.5) } else { (1.5, 0.0) };
+                    for sign in [-1.0, 1.0].iter() {
+                        write_line(
+                            f,
+                            (x1 + sign * dx, y1 + sign * dy),
+                            (x2 + sign * dx, y2 + sign * dy),
+                            color,
+                            1,
+                            "",
+                        )?;
+                    }
+
+                    continue;
+                }
+                '━' | '┃' | '┏' | '┓' | '┗' | '┛' | '┣' | '┫' | '┳' | '┻' | '╋' => {
+                    (2, "")
+                }
+                '┄' | '┆' | '┈' | '┊' | '╌' | '╎' => (1, " stroke-dasharray=\"4 2\""),
+                '.' | ':' | '·' | '┅' | '┇' | '┉' | '┋' => {
+                    (1, " stroke-dasharray=\"1 2\"")
+                }
+                _ => (1, ""),
+            };
+
+            write_line(f, (x1, y1), (x2, y2), color, stroke, extra)?;
+        }
+
+        for text in &self.texts {
+            let Text { x, y, line } = text;
+            writeln!(
+                f,
+                "    <text x=\"{}\" y=\"{}\" fill=\"{}\" dominant-baseline=\"central\">{}</text>",
+                num(*x as f64 * char_width),
+                num((*y as f64 + 0.5) * line_height),
+                escape(&self.foreground),
+                line,
+            )?;
+        }
+
+        f.write_str("</svg>")
+    }
+}
+
+fn build_cell(
+    cfg: &ColoredConfig,
+    layout: &Layout,
+    pos: Position,
+    text: &str,
+    rects: &mut Vec<Rect>,
+    texts: &mut Vec<Text>,
+) {
+    let (row, col) = pos;
+    let entity = Entity::Cell(row, col);
+
+    let (x, y, width, height) = layout.cell_area(cfg, pos);
+
+    let cell_style = cfg
+        .get_colors()
+        .get(&pos)
+        .map(|color| SgrStyle::parse(color.get_prefix()))
+        .unwrap_or_default();
+
+    if let Some(bg) = cell_style.bg {
+        rects.push(Rect {
+            x,
+            y,
+            width,
+            height,
+            color: hex(bg),
+        });
+    }
+
+    let stripped = strip_ansi(text);
+
+    let pad = cfg.get_padding(entity);
+    let fmt = cfg.get_formatting(entity);
+    let alignh = *cfg.get_alignment_horizontal(entity);
+    let alignv = *cfg.get_alignment_vertical(entity);
+
+    let available_width = width.saturating_sub(pad.left.indent.size + pad.right.indent.size);
+    let available_height = height.saturating_sub(pad.top.indent.size + pad.bottom.indent.size);
+
+    let top =
+        y + pad.top.indent.size + indent_top(alignv, count_lines(&stripped), available_height);
+    let block_indent = indent(alignh, string_width_multiline(&stripped), available_width);
+
+    let mut style = cell_style;
+    for (i, line) in get_lines(text).enumerate() {
+        if i >= available_height {
+            break;
+        }
+
+        let line_width = string_width(&strip_ansi(&line));
+        let left = if fmt.allow_lines_alignment {
+            indent(alignh, line_width, available_width)
+        } else {
+            block_indent
+        };
+
+        let x = x + pad.left.indent.size + left;
+        let y = top + i;
+
+        let mut buf = String::new();
+        let mut offset = 0;
+        for part in split_ansi(&line) {
+            let text = match part {
+                AnsiPart::Sgr(params) => {
+                    style.apply(params);
+                    continue;
+                }
+                AnsiPart::Text(text) => text,
+            };
+
+            let text_width = string_width(text);
+
+            if style.bg != cell_style.bg {
+                if let Some(bg) = style.bg {
+                    rects.push(Rect {
+                        x: x + offset,
+                        y,
+                        width: text_width,
+                        height: 1,
+                        color: hex(bg),
+                    });
+                }
+            }
+
+            let attributes = tspan_attributes(&style);
+            if attributes.is_empty() {
+                buf.push_str(&escape(text));
+            } else {
+                buf.push_str(&format!("<tspan{}>{}</tspan>", attributes, escape(text)));
+            }
+
+            offset += text_width;
+        }
+
+        if !buf.is_empty() {
+            texts.push(Text { x, y, line: buf });
+        }
+    }
+}
+
+fn build_lines(cfg: &GridConfig, layout: &Layout) -> Vec<Line> {
+    let count_rows = layout.heights.len();
+    let count_columns = layout.widths.len();
+    let shape = (count_rows, count_columns);
+
+    let mut lines = Vec::new();
+
+    for row in 0..=count_rows {
+        if !cfg.has_horizontal(row, count_rows) {
+            continue;
+        }
+
+        let y = layout.hline_y[row].expect("must be set") as f64 + 0.5;
+
+        for col in 0..count_columns {
+            if row < count_rows && is_covered_by_row_span(cfg, (row, col)) {
+                continue;
+            }
+
+            let c = match cfg.get_horizontal((row, col), count_rows) {
+                Some(c) if c != ' ' => c,
+                _ => continue,
+            };
+
+            let color = cfg
+                .get_horizontal_color((row, col), count_rows)
+                .or_else(|| cfg.get_intersection_color((row, col), shape))
+                .and_then(|color| SgrStyle::parse(color.get_prefix()).fg)
+                .map(hex);
+
+            let (x1, x2) = layout.hline_range(col);
+            lines.push(Line {
+                x1,
+                y1: y,
+                x2,
+                y2: y,
+                c,
+                color,
+            });
+        }
+    }
+
+    for col in 0..=count_columns {
+        if !cfg.has_vertical(col, count_columns) {
+            continue;
+        }
+
+        let x = layout.vline_x[col].expect("must be set") as f64 + 0.5;
+
+        for row in 0..count_rows {
+            if col < count_columns && is_covered_by_column_span(cfg, (row, col)) {
+                continue;
+            }
+
+            let c = match cfg.get_vertical((row, col), count_columns) {
+                Some(c) if c != ' ' => c,
+                _ => continue,
+            };
+
+            let color = cfg
+                .get_vertical_color((row, col), count_columns)
+                .and_then(|color| SgrStyle::parse(color.get_prefix()).fg)
+                .map(hex);
+
+            let (y1, y2) = layout.vline_range(row);
+            lines.push(Line {
+                x1: x,
+                y1,
+                x2: x,
+                y2,
+                c,
+                color,
+            });
+        }
+    }
+
+    lines
+}
+
+/// A layout of a table in characters.
+struct Layout {
+    width: usize,
+    height: usize,
+    col_x: Vec<usize>,
+    row_y: Vec<usize>,
+    vline_x: Vec<Option<usize>>,
+    hline_y: Vec<Option<usize>>,
+    widths: Vec<usize>,
+    heights: Vec<usize>,
+}
+
+impl Layout {
+    fn new(cfg: &GridConfig, widths: Vec<usize>, heights: Vec<usize>) -> Self {
+        let margin = cfg.get_margin();
+
+        let (col_x, vline_x, width) = build_positions(&widths, margin.left.indent.size, |i| {
+            cfg.has_vertical(i, widths.len())
+        });
+        let (row_y, hline_y, height) = build_positions(&heights, margin.top.indent.size, |i| {
+            cfg.has_horizontal(i, heights.len())
+        });
+
+        Self {
+            width: width + margin.right.indent.size,
+            height: height + margin.bottom.indent.size,
+            col_x,
+            row_y,
+            vline_x,
+            hline_y,
+            widths,
+            heights,
+        }
+    }
+
+    /// Returns x, y, width and height of a cell including spans.
+    fn cell_area(&self, cfg: &GridConfig, pos: Position) -> (usize, usize, usize, usize) {
+        let (row, col) = pos;
+        let col_span = cfg.get_column_span(pos).unwrap_or(1).max(1);
+        let row_span = cfg.get_row_span(pos).unwrap_or(1).max(1);
+
+        let last_col = (col + col_span - 1).min(self.widths.len() - 1);
+        let last_row = (row + row_span - 1).min(self.heights.len() - 1);
+
+        let x = self.col_x[col];
+        let y = self.row_y[row];
+        let width = self.col_x[last_col] + self.widths[last_col] - x;
+        let height = self.row_y[last_row] + self.heights[last_row] - y;
+
+        (x, y, width, height)
+    }
+
+    /// Returns a start and an end of a horizontal line segment over a column.
+    fn hline_range(&self, col: usize) -> (f64, f64) {
+        line_range(&self.col_x, &self.widths, &self.vline_x, col)
+    }
+
+    /// Returns a start and an end of a vertical line segment over a row.
+    fn vline_range(&self, row: usize) -> (f64, f64) {
+        line_range(&self.row_y, &self.heights, &self.hline_y, row)
+    }
+}
+
+/// Calculates positions of cells and lines along an axis.
+///
+/// It returns cell positions, line positions and a total length.
+fn build_positions(
+    sizes: &[usize],
+    offset: usize,
+    has_line: impl Fn(usize) -> bool,
+) -> (Vec<usize>, Vec<Option<usize>>, usize) {
+    let mut cells = Vec::with_capacity(sizes.len());
+    let mut lines = Vec::with_capacity(sizes.len() + 1);
+
+    let mut pos = offset;
+    for (i, size) in sizes.iter().enumerate() {
+        if has_line(i) {
+            lines.push(Some(pos));
+            pos += 1;
+        } else {
+            lines.push(None);
+        }
+
+        cells.push(pos);
+        pos += size;
+    }
+
+    if has_line(sizes.len()) {
+        lines.push(Some(pos));
+        pos += 1;
+    } else {
+        lines.push(None);
+    }
+
+    (cells, lines, pos)
+}
+
+/// Calculates a line segment which goes from a middle of one crossing line to the next one.
+fn line_range(cells: &[usize], sizes: &[usize], lines: &[Option<usize>], i: usize) -> (f64, f64) {
+    let start = match lines[i] {
+        Some(pos) => pos as f64 + 0.5,
+        None => cells[i] as f64,
+    };
+
+    let end = match lines[i + 1] {
+        Some(pos) => pos as f64 + 0.5,
+        None => (cells[i] + sizes[i]) as f64,
+    };
+
+    (start, end)
+}
+
+#[derive(Debug, Clone)]
+struct Rect {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    color: String,
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    c: char,
+    color: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Text {
+    x: usize,
+    y: usize,
+    line: String,
+}
+
+fn write_line(
+    f: &mut fmt::Formatter<'_>,
+    (x1, y1): (f64, f64),
+    (x2, y2): (f64, f64),
+    color: &str,
+    width: usize,
+    extra: &str,
+) -> fmt::Result {
+    writeln!(
+        f,
+        "    <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\"{}/>",
+        num(x1),
+        num(y1),
+        num(x2),
+        num(y2),
+        escape(color),
+        width,
+        extra,
+    )
+}
+
+fn tspan_attributes(style: &SgrStyle) -> String {
+    let mut buf = String::new();
+
+    if let Some(fg) = style.fg {
+        buf.push_str(&format!(" fill=\"{}\"", hex(fg)));
+    }
+
+    if style.bold {
+        buf.push_str(" font-weight=\"bold\"");
+    }
+
+    if style.italic {
+        buf.push_str(" font-style=\"italic\"");
+    }
+
+    let decoration = match (style.underline, style.strikethrough) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    };
+
+    if let Some(decoration) = decoration {
+        buf.push_str(&format!(" text-decoration=\"{}\"", decoration));
+    }
+
+    buf
+}
+
+/// Returns a hex representation of a color.
+fn hex(color: SgrColor) -> String {
+    let (r, g, b) = color.to_rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn is_covered_by_row_span(cfg: &GridConfig, pos: Position) -> bool {
+    cfg.is_cell_covered_by_row_span(pos) || cfg.is_cell_covered_by_both_spans(pos)
+}
+
+fn is_covered_by_column_span(cfg: &GridConfig, pos: Position) -> bool {
+    cfg.is_cell_covered_by_column_span(pos) || cfg.is_cell_covered_by_both_spans(pos)
+}
+
+fn indent_top(alignment: AlignmentVertical, size: usize, available: usize) -> usize {
+    let diff = available.saturating_sub(size);
+    match alignment {
+        AlignmentVertical::Top => 0,
+        AlignmentVertical::Bottom => diff,
+        AlignmentVertical::Center => diff / 2,
+    }
+}
+
+fn indent(alignment: AlignmentHorizontal, size: usize, available: usize) -> usize {
+    let diff = available.saturating_sub(size);
+    match alignment {
+        AlignmentHorizontal::Left => 0,
+        AlignmentHorizontal::Right => diff,
+        AlignmentHorizontal::Center => diff / 2,
+    }
+}
+
+/// Formats a number with up to 2 decimal places.
+fn num(n: f64) -> String {
+    let s = format!("{:.2}", n);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+
+    s.to_owned()
+}
+
+fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&#39;"),
+            c => buf.push(c),
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,120 @@
+use table_to_svg::SvgTable;
+use tabled::{
+    settings::{
+        object::{Cell, Rows},
+        Color, Modify, Padding, Span, Style,
+    },
+    Table,
+};
+
+#[test]
+fn svg_table_iter() {
+    let svg = SvgTable::new([["a", "b"]]).to_string();
+
+    assert_eq!(
+        svg,
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"75.6\" height=\"50.4\" viewBox=\"0 0 75.6 50.4\" font-family=\"monospace\" font-size=\"14\" xml:space=\"preserve\">\n",
+            "    <line x1=\"4.2\" y1=\"8.4\" x2=\"37.8\" y2=\"8.4\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"37.8\" y1=\"8.4\" x2=\"71.4\" y2=\"8.4\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"4.2\" y1=\"42\" x2=\"37.8\" y2=\"42\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"37.8\" y1=\"42\" x2=\"71.4\" y2=\"42\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"4.2\" y1=\"8.4\" x2=\"4.2\" y2=\"42\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"37.8\" y1=\"8.4\" x2=\"37.8\" y2=\"42\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"71.4\" y1=\"8.4\" x2=\"71.4\" y2=\"42\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <text x=\"16.8\" y=\"25.2\" fill=\"#000000\" dominant-baseline=\"central\">a</text>\n",
+            "    <text x=\"50.4\" y=\"25.2\" fill=\"#000000\" dominant-baseline=\"central\">b</text>\n",
+            "</svg>",
+        )
+    );
+}
+
+#[test]
+fn svg_table_colors_and_spans() {
+    let color = Color::new(String::from("\u{1b}[44;37m"), String::from("\u{1b}[0m"));
+
+    let mut table = Table::new([["a", "b"], ["c", "d"]]);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Cell::new(1, 0)).with(Span::row(2)))
+        .with(Modify::new(Rows::first()).with(color));
+
+    let mut svg = SvgTable::from(&table);
+    svg.set_font_size(10);
+    svg.set_background(Some("#ffffff"));
+
+    assert_eq!(
+        svg.to_string(),
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"54\" height=\"84\" viewBox=\"0 0 54 84\" font-family=\"monospace\" font-size=\"10\" xml:space=\"preserve\">\n",
+            "    <rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n",
+            "    <rect x=\"6\" y=\"12\" width=\"18\" height=\"12\" fill=\"#000080\"/>\n",
+            "    <rect x=\"30\" y=\"12\" width=\"18\" height=\"12\" fill=\"#000080\"/>\n",
+            "    <line x1=\"3\" y1=\"6\" x2=\"27\" y2=\"6\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"6\" x2=\"51\" y2=\"6\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"3\" y1=\"30\" x2=\"27\" y2=\"30\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"30\" x2=\"51\" y2=\"30\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"54\" x2=\"51\" y2=\"54\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"3\" y1=\"78\" x2=\"27\" y2=\"78\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"78\" x2=\"51\" y2=\"78\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"3\" y1=\"6\" x2=\"3\" y2=\"30\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"3\" y1=\"30\" x2=\"3\" y2=\"54\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"3\" y1=\"54\" x2=\"3\" y2=\"78\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"6\" x2=\"27\" y2=\"30\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"30\" x2=\"27\" y2=\"54\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"27\" y1=\"54\" x2=\"27\" y2=\"78\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"51\" y1=\"6\" x2=\"51\" y2=\"30\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"51\" y1=\"30\" x2=\"51\" y2=\"54\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <line x1=\"51\" y1=\"54\" x2=\"51\" y2=\"78\" stroke=\"#000000\" stroke-width=\"1\"/>\n",
+            "    <text x=\"12\" y=\"18\" fill=\"#000000\" dominant-baseline=\"central\"><tspan fill=\"#c0c0c0\">0</tspan></text>\n",
+            "    <text x=\"36\" y=\"18\" fill=\"#000000\" dominant-baseline=\"central\"><tspan fill=\"#c0c0c0\">1</tspan></text>\n",
+            "    <text x=\"12\" y=\"42\" fill=\"#000000\" dominant-baseline=\"central\">a</text>\n",
+            "    <text x=\"36\" y=\"42\" fill=\"#000000\" dominant-baseline=\"central\">b</text>\n",
+            "    <text x=\"36\" y=\"66\" fill=\"#000000\" dominant-baseline=\"central\">d</text>\n",
+            "</svg>",
+        )
+    );
+}
+
+#[test]
+fn svg_table_ansi_text() {
+    let text = "\u{1b}[1;38;5;196mred\u{1b}[0m\n\u{1b}[4;48;2;0;0;255mblue\u{1b}[0m";
+
+    let mut table = Table::new([[text]]);
+    table.with(Style::blank()).with(Padding::zero());
+
+    let mut svg = SvgTable::from(&table);
+    svg.set_foreground("white");
+    svg.set_font_family("Fira Code");
+
+    assert_eq!(
+        svg.to_string(),
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"33.6\" height=\"50.4\" viewBox=\"0 0 33.6 50.4\" font-family=\"Fira Code\" font-size=\"14\" xml:space=\"preserve\">\n",
+            "    <rect x=\"0\" y=\"33.6\" width=\"33.6\" height=\"16.8\" fill=\"#0000ff\"/>\n",
+            "    <text x=\"0\" y=\"8.4\" fill=\"white\" dominant-baseline=\"central\">0</text>\n",
+            "    <text x=\"0\" y=\"25.2\" fill=\"white\" dominant-baseline=\"central\"><tspan fill=\"#ff0000\" font-weight=\"bold\">red</tspan></text>\n",
+            "    <text x=\"0\" y=\"42\" fill=\"white\" dominant-baseline=\"central\"><tspan text-decoration=\"underline\">blue</tspan></text>\n",
+            "</svg>",
+        )
+    );
+}
+
+#[test]
+fn svg_table_double_border() {
+    let mut table = Table::new([["a"]]);
+    table.with(Style::extended());
+
+    let svg = SvgTable::from(&table).to_string();
+
+    // a double line is drawn as 2 parallel lines
+    assert!(svg.contains("<line x1=\"4.2\" y1=\"6.9\" x2=\"37.8\" y2=\"6.9\" stroke=\"#000000\" stroke-width=\"1\"/>"));
+    assert!(svg.contains("<line x1=\"4.2\" y1=\"9.9\" x2=\"37.8\" y2=\"9.9\" stroke=\"#000000\" stroke-width=\"1\"/>"));
+}
+
+#[test]
+fn svg_table_escaping() {
+    let svg = SvgTable::new([["<a & b>"]]).to_string();
+
+    assert!(svg.contains(">&lt;a &amp; b&gt;</text>"));
+}
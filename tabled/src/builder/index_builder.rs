@@ -177,6 +177,16 @@ impl IndexBuilder {
         self
     }
 
+    /// Checks whether an index column is going to be printed.
+    pub fn is_index_visible(&self) -> bool {
+        self.print_index
+    }
+
+    /// Checks whether index and columns were transposed.
+    pub fn is_transposed(&self) -> bool {
+        self.transposed
+    }
+
     /// Builds a table.
     pub fn build(self) -> Table {
         let builder: Builder = self.into();
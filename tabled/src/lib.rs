@@ -396,6 +396,24 @@ pub use crate::{tabled::Tabled, tables::table::Table};
 /// }
 /// ```
 ///
+/// ### Hyperlinks
+///
+/// You can make a field a clickable link (in terminals supporting OSC 8 escape sequences)
+/// by referencing a field which holds its url, using `#[tabled(link = "url_field")]`.
+/// For tuple structs a field index is used instead of a name.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Crate {
+///    #[tabled(link = "url")]
+///    name: &'static str,
+///    #[tabled(skip)]
+///    url: &'static str,
+/// }
+/// ```
+///
 /// ### Format fields
 ///
 /// As was said already, using `#[derive(Tabled)]` is possible only when all fields implement a `Display` trait.
@@ -133,6 +133,22 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Returns a horizontal alignment if it's a horizontal one.
+    pub const fn get_horizontal(&self) -> Option<AlignmentHorizontal> {
+        match self.inner {
+            Horizontal(a) => Some(a),
+            Vertical(_) => None,
+        }
+    }
+
+    /// Returns a vertical alignment if it's a vertical one.
+    pub const fn get_vertical(&self) -> Option<AlignmentVertical> {
+        match self.inner {
+            Horizontal(_) => None,
+            Vertical(a) => Some(a),
+        }
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     const fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::new(Horizontal(alignment))
@@ -0,0 +1,248 @@
+//! This module contains a [`Chart`] settings,
+//! which render numeric columns as inline bar charts and sparklines.
+//!
+//! The charts are sized by a current column width,
+//! so a [`Width`] setting applied before a chart limits its size.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{settings::Chart, Table};
+//!
+//! let data = [("disk", "capacity"), ("sda", "100"), ("sdb", "50")];
+//!
+//! let table = Table::new(data).with(Chart::bars(1)).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------+----------+\n",
+//!         "| &str | &str     |\n",
+//!         "+------+----------+\n",
+//!         "| disk | capacity |\n",
+//!         "+------+----------+\n",
+//!         "| sda  | ████████ |\n",
+//!         "+------+----------+\n",
+//!         "| sdb  | ████     |\n",
+//!         "+------+----------+",
+//!     ),
+//! );
+//! ```
+//!
+//! [`Width`]: crate::settings::Width
+
+use crate::{
+    grid::dimension::{Dimension, Estimate},
+    records::{ExactRecords, Records, RecordsMut},
+    settings::{util::parse_number, TableOption},
+    tables::table::{ColoredConfig, TableDimension},
+};
+
+/// Eighths of a block used to draw a fractional part of a bar.
+const BAR_PARTS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+const BAR_FULL: char = '█';
+
+/// Levels of a sparkline from a lowest to a highest.
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A set of settings which render a column as a chart.
+///
+/// Cells which are not numbers (or series of numbers) are left unchanged,
+/// so it's fine to target a whole column including a header.
+#[derive(Debug)]
+pub struct Chart;
+
+impl Chart {
+    /// Renders numbers of a column as horizontal bars scaled to a maximum of the column.
+    ///
+    /// A maximum fills a whole column width, negative numbers give an empty bar.
+    pub fn bars(column: usize) -> Bars {
+        Bars { column }
+    }
+
+    /// Renders series of numbers of a column as sparklines.
+    ///
+    /// A series is a list of numbers separated by commas or spaces, e.g. `1, 5, 2, 8`.
+    /// Each number is scaled between a minimum and a maximum of its series.
+    /// If a series is longer than a column width only the last numbers are shown.
+    ///
+    /// ```
+    /// use tabled::{settings::Chart, Table};
+    ///
+    /// let data = [("load", "1 2 4 8 4 2 1")];
+    ///
+    /// let table = Table::new(data).with(Chart::sparkline(1)).to_string();
+    ///
+    /// assert!(table.contains("| ▁▂▄█▄▂▁ "));
+    /// ```
+    pub fn sparkline(column: usize) -> Sparkline {
+        Sparkline { column }
+    }
+}
+
+/// A setting which renders a column as horizontal bars.
+///
+/// It's created by [`Chart::bars`].
+#[derive(Debug, Clone)]
+pub struct Bars {
+    column: usize,
+}
+
+impl<R> TableOption<R, TableDimension<'static>, ColoredConfig> for Bars
+where
+    R: Records + ExactRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    fn change(
+        &mut self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut TableDimension<'static>,
+    ) {
+        let col = self.column;
+        if col >= records.count_columns() {
+            return;
+        }
+
+        let values = (0..records.count_rows())
+            .map(|row| parse_finite(records.get_cell((row, col)).as_ref()))
+            .collect::<Vec<_>>();
+
+        let max = values
+            .iter()
+            .flatten()
+            .fold(0.0, |max, &n| f64::max(max, n));
+        if max <= 0.0 {
+            return;
+        }
+
+        let width = get_column_width(records, cfg, dims, col);
+
+        for (row, value) in values.into_iter().enumerate() {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let available = width.saturating_sub(get_padding_width(cfg, (row, col)));
+            let bar = make_bar(value / max, available);
+            records.set((row, col), bar);
+        }
+    }
+}
+
+/// A setting which renders a column as sparklines.
+///
+/// It's created by [`Chart::sparkline`].
+#[derive(Debug, Clone)]
+pub struct Sparkline {
+    column: usize,
+}
+
+impl<R> TableOption<R, TableDimension<'static>, ColoredConfig> for Sparkline
+where
+    R: Records + ExactRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    fn change(
+        &mut self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut TableDimension<'static>,
+    ) {
+        let col = self.column;
+        if col >= records.count_columns() {
+            return;
+        }
+
+        let width = get_column_width(records, cfg, dims, col);
+
+        for row in 0..records.count_rows() {
+            let series = match parse_series(records.get_cell((row, col)).as_ref()) {
+                Some(series) => series,
+                None => continue,
+            };
+
+            let available = width.saturating_sub(get_padding_width(cfg, (row, col)));
+            let skip = series.len().saturating_sub(available);
+            let line = make_sparkline(&series[skip..]);
+            records.set((row, col), line);
+        }
+    }
+}
+
+/// Returns a column width either set by previous settings or an estimated one.
+fn get_column_width<R>(
+    records: &R,
+    cfg: &ColoredConfig,
+    dims: &TableDimension<'static>,
+    col: usize,
+) -> usize
+where
+    for<'a> &'a R: Records,
+{
+    let mut dims = dims.clone();
+    dims.estimate(records, cfg);
+    dims.get_width(col)
+}
+
+fn get_padding_width(cfg: &ColoredConfig, pos: (usize, usize)) -> usize {
+    let padding = cfg.get_padding(pos.into());
+    padding.left.indent.size + padding.right.indent.size
+}
+
+fn make_bar(ratio: f64, width: usize) -> String {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let eighths = (ratio * width as f64 * 8.0).round() as usize;
+
+    let mut bar = std::iter::repeat(BAR_FULL)
+        .take(eighths / 8)
+        .collect::<String>();
+    if eighths % 8 > 0 {
+        bar.push(BAR_PARTS[eighths % 8]);
+    }
+
+    bar
+}
+
+fn make_sparkline(series: &[f64]) -> String {
+    let (min, max) = series
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &n| {
+            (f64::min(min, n), f64::max(max, n))
+        });
+
+    let top = (SPARK_LEVELS.len() - 1) as f64;
+
+    series
+        .iter()
+        .map(|&n| {
+            let level = if max > min {
+                ((n - min) / (max - min) * top).round() as usize
+            } else {
+                0
+            };
+
+            SPARK_LEVELS[level]
+        })
+        .collect()
+}
+
+fn parse_series(text: &str) -> Option<Vec<f64>> {
+    let series = text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(parse_finite)
+        .collect::<Option<Vec<_>>>()?;
+
+    if series.is_empty() {
+        return None;
+    }
+
+    Some(series)
+}
+
+fn parse_finite(text: &str) -> Option<f64> {
+    parse_number(text).filter(|n| n.is_finite())
+}
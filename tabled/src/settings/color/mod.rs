@@ -8,12 +8,14 @@ use std::borrow::Cow;
 use crate::{
     grid::{color::AnsiColor, config::Entity},
     records::{ExactRecords, Records, RecordsMut},
-    settings::{CellOption, TableOption},
+    settings::{object::Object, CellOption, TableOption},
     tables::table::ColoredConfig,
 };
 
 /// Color represents a color which can be set to things like [`Border`], [`Padding`] and [`Margin`].
 ///
+/// Colors and text attributes can be combined via `|`.
+///
 /// # Example
 ///
 /// ```
@@ -37,139 +39,90 @@ use crate::{
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Color(AnsiColor<'static>);
 
-// todo: Add | operation to combine colors
-
 #[rustfmt::skip]
 impl Color {
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A black foreground color (`ESC[30m`).
     pub const FG_BLACK:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[30m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A blue foreground color (`ESC[34m`).
     pub const FG_BLUE:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[34m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright black foreground color (`ESC[90m`).
     pub const FG_BRIGHT_BLACK:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[90m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright blue foreground color (`ESC[94m`).
     pub const FG_BRIGHT_BLUE:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[94m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright cyan foreground color (`ESC[96m`).
     pub const FG_BRIGHT_CYAN:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[96m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright green foreground color (`ESC[92m`).
     pub const FG_BRIGHT_GREEN:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[92m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright magenta foreground color (`ESC[95m`).
     pub const FG_BRIGHT_MAGENTA: Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[95m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright red foreground color (`ESC[91m`).
     pub const FG_BRIGHT_RED:     Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[91m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright white foreground color (`ESC[97m`).
     pub const FG_BRIGHT_WHITE:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[97m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright yellow foreground color (`ESC[93m`).
     pub const FG_BRIGHT_YELLOW:  Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[93m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A cyan foreground color (`ESC[36m`).
     pub const FG_CYAN:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[36m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A green foreground color (`ESC[32m`).
     pub const FG_GREEN:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[32m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A magenta foreground color (`ESC[35m`).
     pub const FG_MAGENTA:        Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[35m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A red foreground color (`ESC[31m`).
     pub const FG_RED:            Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[31m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A white foreground color (`ESC[37m`).
     pub const FG_WHITE:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[37m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A yellow foreground color (`ESC[33m`).
     pub const FG_YELLOW:         Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[33m"), Cow::Borrowed("\u{1b}[39m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
 
+    /// A black background color (`ESC[40m`).
     pub const BG_BLACK:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[40m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A blue background color (`ESC[44m`).
     pub const BG_BLUE:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[44m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright black background color (`ESC[100m`).
     pub const BG_BRIGHT_BLACK:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[100m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright blue background color (`ESC[104m`).
     pub const BG_BRIGHT_BLUE:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[104m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright cyan background color (`ESC[106m`).
     pub const BG_BRIGHT_CYAN:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[106m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright green background color (`ESC[102m`).
     pub const BG_BRIGHT_GREEN:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[102m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright magenta background color (`ESC[105m`).
     pub const BG_BRIGHT_MAGENTA: Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[105m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright red background color (`ESC[101m`).
     pub const BG_BRIGHT_RED:     Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[101m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright white background color (`ESC[107m`).
     pub const BG_BRIGHT_WHITE:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[107m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A bright yellow background color (`ESC[103m`).
     pub const BG_BRIGHT_YELLOW:  Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[103m"), Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A cyan background color (`ESC[46m`).
     pub const BG_CYAN:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[46m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A green background color (`ESC[42m`).
     pub const BG_GREEN:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[42m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A magenta background color (`ESC[45m`).
     pub const BG_MAGENTA:        Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[45m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A red background color (`ESC[41m`).
     pub const BG_RED:            Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[41m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A white background color (`ESC[47m`).
     pub const BG_WHITE:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[47m"),  Cow::Borrowed("\u{1b}[49m")));
-    /// A color representation.
-    /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// A yellow background color (`ESC[43m`).
     pub const BG_YELLOW:         Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[43m"),  Cow::Borrowed("\u{1b}[49m")));
+
+    /// A bold text attribute (`ESC[1m`).
+    ///
+    /// Notice that it's reset by `ESC[22m` which also resets [`Color::DIM`],
+    /// so ending one of them inside of another one ends both.
+    pub const BOLD:              Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[1m"),   Cow::Borrowed("\u{1b}[22m")));
+    /// A dim (faint) text attribute (`ESC[2m`).
+    ///
+    /// Notice that it's reset by `ESC[22m` which also resets [`Color::BOLD`],
+    /// so ending one of them inside of another one ends both.
+    pub const DIM:               Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[2m"),   Cow::Borrowed("\u{1b}[22m")));
+    /// An italic text attribute (`ESC[3m`).
+    pub const ITALIC:            Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[3m"),   Cow::Borrowed("\u{1b}[23m")));
+    /// An underline text attribute (`ESC[4m`).
+    pub const UNDERLINE:         Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[4m"),   Cow::Borrowed("\u{1b}[24m")));
+    /// A strikethrough (crossed out) text attribute (`ESC[9m`).
+    pub const STRIKETHROUGH:     Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[9m"),   Cow::Borrowed("\u{1b}[29m")));
 }
 
 impl Color {
@@ -178,6 +131,87 @@ impl Color {
     pub fn new(prefix: String, suffix: String) -> Self {
         Self(AnsiColor::new(prefix.into(), suffix.into()))
     }
+
+    /// Creates a 24 bit (true color) foreground color.
+    ///
+    /// ```
+    /// use tabled::settings::Color;
+    ///
+    /// let color = Color::rgb_fg(255, 0, 0);
+    /// assert_eq!(color.get_prefix(), "\u{1b}[38;2;255;0;0m");
+    /// assert_eq!(color.get_suffix(), "\u{1b}[39m");
+    /// ```
+    pub fn rgb_fg(r: u8, g: u8, b: u8) -> Self {
+        Self::new(
+            format!("\u{1b}[38;2;{};{};{}m", r, g, b),
+            String::from("\u{1b}[39m"),
+        )
+    }
+
+    /// Creates a 24 bit (true color) background color.
+    pub fn rgb_bg(r: u8, g: u8, b: u8) -> Self {
+        Self::new(
+            format!("\u{1b}[48;2;{};{};{}m", r, g, b),
+            String::from("\u{1b}[49m"),
+        )
+    }
+
+    /// Creates a foreground color from a 256 color palette.
+    ///
+    /// ```
+    /// use tabled::settings::Color;
+    ///
+    /// let color = Color::palette_fg(208);
+    /// assert_eq!(color.get_prefix(), "\u{1b}[38;5;208m");
+    /// ```
+    pub fn palette_fg(index: u8) -> Self {
+        Self::new(
+            format!("\u{1b}[38;5;{}m", index),
+            String::from("\u{1b}[39m"),
+        )
+    }
+
+    /// Creates a background color from a 256 color palette.
+    pub fn palette_bg(index: u8) -> Self {
+        Self::new(
+            format!("\u{1b}[48;5;{}m", index),
+            String::from("\u{1b}[49m"),
+        )
+    }
+
+    /// Gets a reference to a prefix.
+    pub fn get_prefix(&self) -> &str {
+        self.0.get_prefix()
+    }
+
+    /// Gets a reference to a suffix.
+    pub fn get_suffix(&self) -> &str {
+        self.0.get_suffix()
+    }
+}
+
+impl std::ops::BitOr for Color {
+    type Output = Self;
+
+    /// Combines 2 colors (or text attributes) into one.
+    ///
+    /// Prefixes are printed in order,
+    /// while suffixes are printed in reverse order so the styles are closed like nested ones.
+    ///
+    /// ```
+    /// use tabled::settings::Color;
+    ///
+    /// let color = Color::BOLD | Color::FG_RED;
+    ///
+    /// assert_eq!(color.get_prefix(), "\u{1b}[1m\u{1b}[31m");
+    /// assert_eq!(color.get_suffix(), "\u{1b}[39m\u{1b}[22m");
+    /// ```
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let prefix = format!("{}{}", self.get_prefix(), rhs.get_prefix());
+        let suffix = format!("{}{}", rhs.get_suffix(), self.get_suffix());
+
+        Self::new(prefix, suffix)
+    }
 }
 
 impl From<Color> for AnsiColor<'static> {
@@ -234,6 +268,99 @@ where
     }
 }
 
+/// RowColor fills whole rows of a [`Table`] with a color,
+/// including vertical borders between cells.
+///
+/// A row spanned cell keeps a color of a row it starts at.
+/// Horizontal lines and a margin are not colored.
+///
+/// ```
+/// use tabled::{settings::{object::Rows, Color, RowColor, Style}, Table};
+///
+/// let mut table = Table::new([[1, 2]]);
+/// table
+///     .with(Style::psql())
+///     .with(RowColor::new(Rows::last(), Color::BG_BLUE));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " 0 | 1 \n",
+///         "---+---\n",
+///         "\u{1b}[44m 1 \u{1b}[49m\u{1b}[44m|\u{1b}[49m\u{1b}[44m 2 \u{1b}[49m",
+///     ),
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct RowColor<O> {
+    target: O,
+    color: Color,
+}
+
+impl<O> RowColor<O> {
+    /// Creates a new [`RowColor`] for rows of a given target.
+    pub fn new(target: O, color: Color) -> Self {
+        Self { target, color }
+    }
+}
+
+impl<O, R, D> TableOption<R, D, ColoredConfig> for RowColor<O>
+where
+    O: Object<R>,
+    R: Records + ExactRecords,
+{
+    fn change(&mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+
+        for entity in self.target.cells(records) {
+            let rows = match entity {
+                Entity::Row(row) | Entity::Cell(row, _) => row..row + 1,
+                Entity::Global | Entity::Column(_) => 0..count_rows,
+            };
+
+            for row in rows {
+                cfg.set_row_color(row, self.color.0.clone());
+            }
+        }
+    }
+}
+
+/// A color depth a [`Table`] is rendered with.
+///
+/// Colors which a terminal doesn't support are downgraded to the closest ones,
+/// or stripped with [`ColorDepth::None`].
+///
+/// ```
+/// use tabled::{settings::{Color, ColorDepth, Modify, object::Rows}, Table};
+///
+/// let mut table = Table::new([["Hello"]]);
+/// table
+///     .with(Modify::new(Rows::last()).with(Color::rgb_fg(255, 0, 0)))
+///     .with(ColorDepth::Ansi16);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-------+\n",
+///         "| 0     |\n",
+///         "+-------+\n",
+///         "| \u{1b}[91mHello\u{1b}[39m |\n",
+///         "+-------+",
+///     ),
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+pub use papergrid::color::ColorDepth;
+
+impl<R, D> TableOption<R, D, ColoredConfig> for ColorDepth {
+    fn change(&mut self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.set_color_depth(*self);
+    }
+}
+
 impl papergrid::color::Color for Color {
     fn fmt_prefix<W: std::fmt::Write>(&self, f: &mut W) -> std::fmt::Result {
         self.0.fmt_prefix(f)
@@ -0,0 +1,395 @@
+//! This module contains a [`Conditional`] settings,
+//! which are applied to cells depending on their values.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     settings::{conditional::Conditional, object::Columns, Color, Modify},
+//!     Table,
+//! };
+//!
+//! let data = [("Debian", -2), ("Arch", 3)];
+//!
+//! let table = Table::new(data)
+//!     .with(Modify::new(Columns::single(1)).with(Conditional::number(|n| n < 0.0, Color::FG_RED)))
+//!     .with(Modify::new(Columns::single(1)).with(Conditional::max(Color::BOLD)))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+--------+-----+\n",
+//!         "| &str   | i32 |\n",
+//!         "+--------+-----+\n",
+//!         "| Debian | \u{1b}[31m-2\u{1b}[39m  |\n",
+//!         "+--------+-----+\n",
+//!         "| Arch   | \u{1b}[1m3\u{1b}[22m   |\n",
+//!         "+--------+-----+",
+//!     ),
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+    grid::config::{Entity, Position},
+    records::{ExactRecords, Records, RecordsMut},
+    settings::{util::parse_number, CellOption, Color},
+    tables::table::ColoredConfig,
+};
+
+/// A set of settings which are applied depending on a cell value.
+///
+/// Numeric settings parse a cell text as a number (ignoring surrounding spaces),
+/// cells which are not numbers are left unchanged.
+/// So it's fine to target a whole column including a header.
+///
+/// [`Conditional::max`], [`Conditional::min`] and [`Conditional::gradient`]
+/// compare numbers within a target only,
+/// so they're meant to be used with columns rather than rows or single cells.
+#[derive(Debug)]
+pub struct Conditional;
+
+impl Conditional {
+    /// Applies a setting to cells which text matches a predicate.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{conditional::Conditional, object::Segment, Color, Modify},
+    ///     Table,
+    /// };
+    ///
+    /// let table = Table::new(["ok", "failed"])
+    ///     .with(Modify::new(Segment::all()).with(Conditional::when(|s| s == "failed", Color::FG_RED)))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[31mfailed\u{1b}[39m"));
+    /// ```
+    pub fn when<P, O>(predicate: P, option: O) -> When<P, O>
+    where
+        P: FnMut(&str) -> bool,
+    {
+        When { predicate, option }
+    }
+
+    /// Applies a setting to cells which are numbers matching a predicate.
+    pub fn number<P, O>(mut predicate: P, option: O) -> When<impl FnMut(&str) -> bool, O>
+    where
+        P: FnMut(f64) -> bool,
+    {
+        Self::when(
+            move |text| parse_number(text).map_or(false, &mut predicate),
+            option,
+        )
+    }
+
+    /// Applies a setting to cells which hold a maximum number in their targeted column.
+    pub fn max<O>(option: O) -> Extremum<O> {
+        Extremum {
+            option,
+            is_max: true,
+        }
+    }
+
+    /// Applies a setting to cells which hold a minimum number in their targeted column.
+    pub fn min<O>(option: O) -> Extremum<O> {
+        Extremum {
+            option,
+            is_max: false,
+        }
+    }
+
+    /// Colors numbers by their position between a minimum and a maximum of their targeted column (a heatmap).
+    ///
+    /// A minimum gets a `from` color and a maximum gets a `to` color,
+    /// the rest get a color in between.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{conditional::Conditional, object::Columns, Modify},
+    ///     Table,
+    /// };
+    ///
+    /// let table = Table::new([0, 5, 10])
+    ///     .with(Modify::new(Columns::single(0)).with(Conditional::gradient((0, 0, 0), (255, 0, 0))))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[38;2;128;0;0m5\u{1b}[39m"));
+    /// ```
+    pub fn gradient(from: (u8, u8, u8), to: (u8, u8, u8)) -> Gradient {
+        Gradient {
+            from,
+            to,
+            background: false,
+        }
+    }
+
+    /// Appends an arrow to numbers showing whether they went up or down,
+    /// comparing with a number in a previous row.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{conditional::Conditional, object::Rows, Modify},
+    ///     Table,
+    /// };
+    ///
+    /// let table = Table::new([1, 3, 2, 2])
+    ///     .with(Modify::new(Rows::new(1..)).with(Conditional::trend()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----+\n",
+    ///         "| i32 |\n",
+    ///         "+-----+\n",
+    ///         "| 1   |\n",
+    ///         "+-----+\n",
+    ///         "| 3 ↑ |\n",
+    ///         "+-----+\n",
+    ///         "| 2 ↓ |\n",
+    ///         "+-----+\n",
+    ///         "| 2 → |\n",
+    ///         "+-----+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn trend() -> Trend {
+        Trend {
+            up: '↑',
+            down: '↓',
+            same: '→',
+        }
+    }
+}
+
+/// A setting which is applied to cells matching a predicate.
+///
+/// It's created by [`Conditional::when`] or [`Conditional::number`].
+#[derive(Debug)]
+pub struct When<P, O> {
+    predicate: P,
+    option: O,
+}
+
+impl<P, O, R, C> CellOption<R, C> for When<P, O>
+where
+    P: FnMut(&str) -> bool,
+    O: CellOption<R, C>,
+    R: Records + ExactRecords,
+{
+    fn change(&mut self, records: &mut R, cfg: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = records.get_cell(pos).as_ref();
+            if (self.predicate)(text) {
+                self.option.change(records, cfg, pos.into());
+            }
+        }
+    }
+}
+
+/// A setting which is applied to a maximum or a minimum number of a column.
+///
+/// It's created by [`Conditional::max`] or [`Conditional::min`].
+#[derive(Debug)]
+pub struct Extremum<O> {
+    option: O,
+    is_max: bool,
+}
+
+impl<O, R, C> CellOption<R, C> for Extremum<O>
+where
+    O: CellOption<R, C>,
+    R: Records + ExactRecords,
+{
+    fn change(&mut self, records: &mut R, cfg: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        let cells = entity.iter(count_rows, count_cols).collect::<Vec<_>>();
+        let ranges = column_ranges(records, &cells);
+
+        for pos in cells {
+            let value = match parse_number(records.get_cell(pos).as_ref()) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let (min, max) = match ranges.get(&pos.1) {
+                Some(&range) => range,
+                None => continue,
+            };
+
+            let extremum = if self.is_max { max } else { min };
+            if value == extremum {
+                self.option.change(records, cfg, pos.into());
+            }
+        }
+    }
+}
+
+/// A heatmap setting which colors numbers from a minimum to a maximum of a column.
+///
+/// It's created by [`Conditional::gradient`].
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    from: (u8, u8, u8),
+    to: (u8, u8, u8),
+    background: bool,
+}
+
+impl Gradient {
+    /// Colors a background instead of a text.
+    pub fn background(mut self) -> Self {
+        self.background = true;
+        self
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for Gradient
+where
+    R: Records + ExactRecords,
+{
+    fn change(&mut self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        let cells = entity.iter(count_rows, count_cols).collect::<Vec<_>>();
+        let ranges = column_ranges(records, &cells);
+
+        for pos in cells {
+            let value = match parse_number(records.get_cell(pos).as_ref()) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let (min, max) = match ranges.get(&pos.1) {
+                Some(&range) => range,
+                None => continue,
+            };
+
+            let ratio = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.0
+            };
+
+            let lerp = |a: u8, b: u8| {
+                let (a, b) = (f64::from(a), f64::from(b));
+                (a + (b - a) * ratio).round() as u8
+            };
+
+            let (r, g, b) = (
+                lerp(self.from.0, self.to.0),
+                lerp(self.from.1, self.to.1),
+                lerp(self.from.2, self.to.2),
+            );
+
+            let color = if self.background {
+                Color::rgb_bg(r, g, b)
+            } else {
+                Color::rgb_fg(r, g, b)
+            };
+
+            let _ = cfg.set_color(pos, color.into());
+        }
+    }
+}
+
+/// A setting which appends an arrow showing a change from a previous row.
+///
+/// It's created by [`Conditional::trend`].
+#[derive(Debug, Clone)]
+pub struct Trend {
+    up: char,
+    down: char,
+    same: char,
+}
+
+impl Trend {
+    /// Sets symbols used for an increase, a decrease and no change.
+    pub fn symbols(mut self, up: char, down: char, same: char) -> Self {
+        self.up = up;
+        self.down = down;
+        self.same = same;
+        self
+    }
+
+    /// Parses a number ignoring an arrow which could be already appended.
+    fn parse(&self, text: &str) -> Option<f64> {
+        let text = text.trim_end();
+        let text = text
+            .strip_suffix(self.up)
+            .or_else(|| text.strip_suffix(self.down))
+            .or_else(|| text.strip_suffix(self.same))
+            .unwrap_or(text);
+
+        parse_number(text)
+    }
+}
+
+impl<R, C> CellOption<R, C> for Trend
+where
+    R: Records + ExactRecords + RecordsMut<String>,
+{
+    fn change(&mut self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for (row, col) in entity.iter(count_rows, count_cols) {
+            if row == 0 {
+                continue;
+            }
+
+            let text = records.get_cell((row, col)).as_ref();
+            let value = match parse_number(text) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let prev = match self.parse(records.get_cell((row - 1, col)).as_ref()) {
+                Some(prev) => prev,
+                None => continue,
+            };
+
+            let arrow = if value > prev {
+                self.up
+            } else if value < prev {
+                self.down
+            } else {
+                self.same
+            };
+
+            let text = format!("{} {}", text, arrow);
+            records.set((row, col), text);
+        }
+    }
+}
+
+/// Returns a minimum and a maximum number of each column among the given cells.
+fn column_ranges<R>(records: &R, cells: &[Position]) -> HashMap<usize, (f64, f64)>
+where
+    R: Records + ExactRecords,
+{
+    let mut ranges = HashMap::new();
+    for &pos in cells {
+        let value = match parse_number(records.get_cell(pos).as_ref()) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let _ = ranges
+            .entry(pos.1)
+            .and_modify(|(min, max): &mut (f64, f64)| {
+                *min = f64::min(*min, value);
+                *max = f64::max(*max, value);
+            })
+            .or_insert((value, value));
+    }
+
+    ranges
+}
@@ -0,0 +1,39 @@
+use crate::{settings::TableOption, tables::table::ColoredConfig};
+
+/// A width of East Asian ambiguous characters (like box-drawing characters, `±` or `§`)
+/// a [`Table`] is rendered with.
+///
+/// By default they're considered [`AmbiguousWidth::Narrow`].
+/// Terminals configured for CJK locales usually render them as 2 columns,
+/// in which case [`AmbiguousWidth::Wide`] must be used.
+///
+/// It's respected by a dimension estimation, borders and [`Width`] settings,
+/// so it must be set before [`Width`] settings are applied.
+///
+/// ```
+/// use tabled::{settings::{formatting::AmbiguousWidth, Style}, Table};
+///
+/// let table = Table::new(["±1"])
+///     .with(Style::markdown())
+///     .with(AmbiguousWidth::Wide)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "| &str |\n",
+///         "|------|\n",
+///         "| ±1  |",
+///     ),
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Width`]: crate::settings::Width
+pub use crate::grid::util::string::AmbiguousWidth;
+
+impl<R, D> TableOption<R, D, ColoredConfig> for AmbiguousWidth {
+    fn change(&mut self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.set_ambiguous_width(*self);
+    }
+}
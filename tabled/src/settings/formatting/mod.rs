@@ -6,14 +6,18 @@
 //!
 //! [`Charset`] responsible for special char treatment.
 //!
+//! [`AmbiguousWidth`] sets a width of East Asian ambiguous characters.
+//!
 //! [`Alignment`]: crate::settings::alignment::Alignment
 
 mod alignment_strategy;
+mod ambiguous_width;
 mod charset;
 mod tab_size;
 mod trim_strategy;
 
 pub use alignment_strategy::AlignmentStrategy;
+pub use ambiguous_width::AmbiguousWidth;
 pub use charset::{Charset, CleanCharset};
 pub use tab_size::TabSize;
 pub use trim_strategy::TrimStrategy;
@@ -0,0 +1,131 @@
+//! This module contains a [`Hyperlink`] setting,
+//! which makes cells clickable links in terminals supporting OSC 8 escape sequences.
+//!
+//! The sequences are not visible so they don't affect a width of a cell,
+//! and they are kept intact by [`Width`] settings.
+//!
+//! See <https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda>.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     settings::{object::Rows, Hyperlink, Modify},
+//!     Table,
+//! };
+//!
+//! let data = [("docs", "https://docs.rs")];
+//!
+//! let table = Table::new(data)
+//!     .with(Modify::new(Rows::new(1..)).with(Hyperlink::url("https://docs.rs")))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------+-----------------+\n",
+//!         "| &str | &str            |\n",
+//!         "+------+-----------------+\n",
+//!         "| \u{1b}]8;;https://docs.rs\u{1b}\\docs\u{1b}]8;;\u{1b}\\ ",
+//!         "| \u{1b}]8;;https://docs.rs\u{1b}\\https://docs.rs\u{1b}]8;;\u{1b}\\ |\n",
+//!         "+------+-----------------+",
+//!     ),
+//! );
+//! ```
+//!
+//! [`Width`]: crate::settings::Width
+
+use crate::{
+    grid::config::Entity,
+    records::{ExactRecords, Records, RecordsMut},
+    settings::CellOption,
+};
+
+/// A set of settings which wrap cells into terminal hyperlinks.
+///
+/// Each line of a cell is wrapped separately,
+/// so multiline cells stay aligned.
+#[derive(Debug)]
+pub struct Hyperlink;
+
+impl Hyperlink {
+    /// Makes cells a link to a given url.
+    pub fn url<S: Into<String>>(url: S) -> HyperlinkUrl<impl FnMut(&str) -> String> {
+        let url = url.into();
+        HyperlinkUrl::new(move |_: &str| url.clone())
+    }
+
+    /// Makes cells a link to a url built from a cell content.
+    ///
+    /// If a returned url is empty a cell is left unchanged.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{object::Columns, Hyperlink, Modify},
+    ///     Table,
+    /// };
+    ///
+    /// let data = ["tabled"];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Modify::new(Columns::first()).with(Hyperlink::content(|crate_name| {
+    ///         format!("https://crates.io/crates/{}", crate_name)
+    ///     })))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\tabled"));
+    /// ```
+    pub fn content<F>(f: F) -> HyperlinkUrl<F>
+    where
+        F: FnMut(&str) -> String,
+    {
+        HyperlinkUrl::new(f)
+    }
+}
+
+/// A setting which wraps cells into a hyperlink.
+///
+/// It's created by [`Hyperlink::url`] or [`Hyperlink::content`].
+#[derive(Debug)]
+pub struct HyperlinkUrl<F> {
+    f: F,
+}
+
+impl<F> HyperlinkUrl<F> {
+    fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F, R, C> CellOption<R, C> for HyperlinkUrl<F>
+where
+    F: FnMut(&str) -> String,
+    R: Records + ExactRecords + RecordsMut<String>,
+{
+    fn change(&mut self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = records.get_cell(pos).as_ref();
+            let url = (self.f)(text);
+            if url.is_empty() {
+                continue;
+            }
+
+            let text = make_link(&url, text);
+            records.set(pos, text);
+        }
+    }
+}
+
+/// Wraps each line of a text into an OSC 8 hyperlink.
+///
+/// It's used by a `#[tabled(link = "field")]` derive attribute.
+#[doc(hidden)]
+pub fn make_link(url: &str, text: &str) -> String {
+    text.split('\n')
+        .map(|line| format!("\u{1b}]8;;{}\u{1b}\\{}\u{1b}]8;;\u{1b}\\", url, line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
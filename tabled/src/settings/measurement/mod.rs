@@ -2,7 +2,7 @@
 
 use crate::{
     grid::spanned::{ExactDimension, GridConfig},
-    grid::util::string::{self, string_width_multiline},
+    grid::util::string::{self, string_width_multiline_ambiguous, AmbiguousWidth},
     records::{ExactRecords, Records},
     settings::{Height, Width},
 };
@@ -26,8 +26,8 @@ impl<T> Measurement<T> for usize {
 pub struct Max;
 
 impl Measurement<Width> for Max {
-    fn measure<R: Records + ExactRecords>(&self, records: R, _: &GridConfig) -> usize {
-        grid_widths(&records)
+    fn measure<R: Records + ExactRecords>(&self, records: R, cfg: &GridConfig) -> usize {
+        grid_widths(&records, cfg.get_ambiguous_width())
             .map(|r| r.max().unwrap_or(0))
             .max()
             .unwrap_or(0)
@@ -48,8 +48,8 @@ impl Measurement<Height> for Max {
 pub struct Min;
 
 impl Measurement<Width> for Min {
-    fn measure<R: Records + ExactRecords>(&self, records: R, _: &GridConfig) -> usize {
-        grid_widths(&records)
+    fn measure<R: Records + ExactRecords>(&self, records: R, cfg: &GridConfig) -> usize {
+        grid_widths(&records, cfg.get_ambiguous_width())
             .map(|r| r.min().unwrap_or(0))
             .max()
             .unwrap_or(0)
@@ -91,11 +91,14 @@ impl Measurement<Height> for Percent {
 
 fn grid_widths<R: Records + ExactRecords>(
     records: &R,
+    ambiguous: AmbiguousWidth,
 ) -> impl Iterator<Item = impl Iterator<Item = usize> + '_> + '_ {
     let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
     (0..count_rows).map(move |row| {
-        (0..count_cols)
-            .map(move |col| string_width_multiline(records.get_cell((row, col)).as_ref()))
+        (0..count_cols).map(move |col| {
+            let text = records.get_cell((row, col)).as_ref();
+            string_width_multiline_ambiguous(text, ambiguous)
+        })
     })
 }
 
@@ -65,6 +65,12 @@ mod concat;
 
 pub mod style;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod chart;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod conditional;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod disable;
@@ -82,6 +88,9 @@ pub mod height;
 pub mod highlight;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod hyperlink;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod locator;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -105,6 +114,9 @@ pub mod span;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod width;
 
+#[cfg(feature = "std")]
+mod util;
+
 pub use cell_option::CellOption;
 pub use settings_list::{EmptySettings, Settings};
 pub use table_option::TableOption;
@@ -120,7 +132,8 @@ pub use self::{
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
-    color::Color, concat::Concat, disable::Disable, format::Format, height::Height,
-    highlight::Highlight, merge::Merge, panel::Panel, rotate::Rotate, shadow::Shadow, span::Span,
-    style::Border, width::Width,
+    chart::Chart, color::Color, color::ColorDepth, color::RowColor, concat::Concat,
+    conditional::Conditional, disable::Disable, format::Format, height::Height,
+    highlight::Highlight, hyperlink::Hyperlink, merge::Merge, panel::Panel, rotate::Rotate,
+    shadow::Shadow, span::Span, style::Border, width::Width,
 };
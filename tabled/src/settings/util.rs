@@ -0,0 +1,12 @@
+//! The module contains helpers shared by settings.
+
+/// Parses a number out of a cell text.
+///
+/// Surrounding whitespaces (and ANSI sequences with a `color` feature) are ignored,
+/// and `NaN` is not considered to be a number.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    #[cfg(feature = "color")]
+    let text = ansi_str::AnsiStr::ansi_strip(text);
+
+    text.trim().parse::<f64>().ok().filter(|n| !n.is_nan())
+}
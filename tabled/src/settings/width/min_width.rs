@@ -6,7 +6,7 @@ use std::marker::PhantomData;
 
 use crate::{
     grid::config::Entity,
-    grid::util::string::{get_lines, string_width_multiline},
+    grid::util::string::{get_lines, string_width_multiline_ambiguous, AmbiguousWidth},
     records::{ExactRecords, Records, RecordsMut},
     settings::{
         measurement::Measurement,
@@ -109,18 +109,19 @@ where
 {
     fn change(&mut self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
         let width = self.width.measure(&*records, cfg);
+        let ambiguous = cfg.get_ambiguous_width();
 
         let count_rows = records.count_rows();
         let count_columns = records.count_columns();
 
         for pos in entity.iter(count_rows, count_columns) {
             let cell = records.get_cell(pos).as_ref();
-            let cell_width = string_width_multiline(cell);
+            let cell_width = string_width_multiline_ambiguous(cell, ambiguous);
             if cell_width >= width {
                 continue;
             }
 
-            let content = increase_width(cell, width, self.fill);
+            let content = increase_width(cell, width, self.fill, ambiguous);
             records.set(pos, content);
         }
     }
@@ -177,13 +178,13 @@ where
     widths
 }
 
-fn increase_width(s: &str, width: usize, fill_with: char) -> String {
-    use crate::grid::util::string::string_width;
+fn increase_width(s: &str, width: usize, fill_with: char, ambiguous: AmbiguousWidth) -> String {
+    use crate::grid::util::string::string_width_ambiguous;
     use std::{borrow::Cow, iter::repeat};
 
     get_lines(s)
         .map(|line| {
-            let length = string_width(&line);
+            let length = string_width_ambiguous(&line, ambiguous);
 
             if length < width {
                 let mut line = line.into_owned();
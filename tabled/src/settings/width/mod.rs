@@ -44,7 +44,7 @@ use crate::settings::measurement::Measurement;
 pub use self::{
     justify::Justify,
     min_width::MinWidth,
-    truncate::{SuffixLimit, Truncate},
+    truncate::{SuffixLimit, Truncate, TruncatePosition},
     width_list::WidthList,
     wrap::Wrap,
 };
@@ -7,7 +7,9 @@ use std::{borrow::Cow, iter, marker::PhantomData, ops::Deref};
 use crate::{
     grid::{
         spanned::config::GridConfig,
-        util::string::{string_width, string_width_multiline},
+        util::string::{
+            get_lines, string_width_ambiguous, string_width_multiline_ambiguous, AmbiguousWidth,
+        },
     },
     records::{EmptyRecords, ExactRecords, Records, RecordsMut},
     settings::{
@@ -18,7 +20,9 @@ use crate::{
     tables::table::{ColoredConfig, TableDimension},
 };
 
-use super::util::{cut_str, get_table_widths, get_table_widths_with_total};
+use super::util::{
+    cut_str, cut_str_start, get_table_widths, get_table_widths_with_total, restore_link, strip_osc,
+};
 
 /// Truncate cut the string to a given width if its length exceeds it.
 /// Otherwise keeps the content of a cell untouched.
@@ -42,6 +46,7 @@ use super::util::{cut_str, get_table_widths, get_table_widths_with_total};
 pub struct Truncate<'a, W = usize, P = PriorityNone> {
     width: W,
     suffix: Option<TruncateSuffix<'a>>,
+    position: TruncatePosition,
     _priority: PhantomData<P>,
 }
 
@@ -64,6 +69,17 @@ impl Default for TruncateSuffix<'_> {
     }
 }
 
+/// A position at which a text is cut by [`Truncate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncatePosition {
+    /// A beginning of a text is cut, so its end is kept.
+    Start,
+    /// A middle of a text is cut, so its beginning and its end are kept.
+    Middle,
+    /// An end of a text is cut.
+    End,
+}
+
 /// A suffix limit settings.
 #[derive(Debug, Clone, Copy)]
 pub enum SuffixLimit {
@@ -84,6 +100,7 @@ where
         Self {
             width,
             suffix: None,
+            position: TruncatePosition::End,
             _priority: PhantomData::default(),
         }
     }
@@ -105,6 +122,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: Some(suff),
+            position: self.position,
             _priority: PhantomData::default(),
         }
     }
@@ -117,6 +135,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: Some(suff),
+            position: self.position,
             _priority: PhantomData::default(),
         }
     }
@@ -130,6 +149,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: Some(suff),
+            position: self.position,
             _priority: PhantomData::default(),
         }
     }
@@ -148,15 +168,47 @@ impl<'a, W, P> Truncate<'a, W, P> {
         Truncate {
             width: self.width,
             suffix: self.suffix,
+            position: self.position,
             _priority: PhantomData::default(),
         }
     }
+
+    /// Sets a position at which a text is cut, by default it's [`TruncatePosition::End`].
+    ///
+    /// A suffix is placed at the cut point.
+    /// [`TruncatePosition::Start`] and [`TruncatePosition::Middle`] are applied to each line of a cell.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{object::Rows, width::{TruncatePosition, Width}, Modify},
+    ///     Table,
+    /// };
+    ///
+    /// let table = Table::new(["/home/user/src/main.rs"])
+    ///     .with(Modify::new(Rows::new(1..)).with(
+    ///         Width::truncate(15).suffix("…").position(TruncatePosition::Middle),
+    ///     ))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-----------------+\n\
+    ///      | &str            |\n\
+    ///      +-----------------+\n\
+    ///      | /home/u…main.rs |\n\
+    ///      +-----------------+"
+    /// );
+    /// ```
+    pub fn position(mut self, position: TruncatePosition) -> Self {
+        self.position = position;
+        self
+    }
 }
 
 impl Truncate<'_, (), ()> {
     /// Truncate a given string
     pub fn truncate_text(text: &str, width: usize) -> Cow<'_, str> {
-        truncate_text(text, width, "", false)
+        truncate_text(text, width, "", false, AmbiguousWidth::Narrow)
     }
 }
 
@@ -173,12 +225,13 @@ where
         entity: papergrid::config::Entity,
     ) {
         let truncate_width = self.width.measure(&*records, cfg);
+        let ambiguous = cfg.get_ambiguous_width();
 
         let mut width = truncate_width;
         let mut suffix = Cow::Borrowed("");
 
         if let Some(x) = self.suffix.as_ref() {
-            let (s, w) = make_suffix(x, width);
+            let (s, w) = make_suffix(x, width, ambiguous);
             suffix = s;
             width = w;
         };
@@ -191,22 +244,44 @@ where
         for pos in entity.iter(count_rows, count_columns) {
             let text = records.get_cell(pos).as_ref();
 
-            let cell_width = string_width_multiline(text);
+            let cell_width = string_width_multiline_ambiguous(text, ambiguous);
             if truncate_width >= cell_width {
                 continue;
             }
 
+            // a hyperlink is cut off and restored afterwards so its sequences are kept intact
+            let (text, url) = strip_osc(text);
+            let text = text.as_str();
+
             let text = if width == 0 {
                 if truncate_width == 0 {
                     Cow::Borrowed("")
                 } else {
                     Cow::Borrowed(suffix.deref())
                 }
+            } else if self.position == TruncatePosition::End {
+                truncate_text(text, width, &suffix, save_suffix_color, ambiguous)
             } else {
-                truncate_text(text, width, &suffix, save_suffix_color)
+                let lines = get_lines(text).map(|line| {
+                    if string_width_ambiguous(&line, ambiguous) <= truncate_width {
+                        return line.into_owned();
+                    }
+
+                    truncate_line(
+                        &line,
+                        width,
+                        &suffix,
+                        self.position,
+                        save_suffix_color,
+                        ambiguous,
+                    )
+                });
+
+                Cow::Owned(lines.collect::<Vec<_>>().join("\n"))
             };
 
-            records.set(pos, text.into_owned());
+            let text = restore_link(text.into_owned(), url);
+            records.set(pos, text);
         }
     }
 }
@@ -222,8 +297,12 @@ fn need_suffix_color_preservation(_suffix: &Option<TruncateSuffix<'_>>) -> bool
     }
 }
 
-fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str>, usize) {
-    let suffix_length = string_width(&suffix.text);
+fn make_suffix<'a>(
+    suffix: &'a TruncateSuffix<'_>,
+    width: usize,
+    ambiguous: AmbiguousWidth,
+) -> (Cow<'a, str>, usize) {
+    let suffix_length = string_width_ambiguous(&suffix.text, ambiguous);
     if width > suffix_length {
         return (Cow::Borrowed(suffix.text.as_ref()), width - suffix_length);
     }
@@ -231,7 +310,7 @@ fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str
     match suffix.limit {
         SuffixLimit::Ignore => (Cow::Borrowed(""), width),
         SuffixLimit::Cut => {
-            let suffix = cut_str(&suffix.text, width);
+            let suffix = cut_str(&suffix.text, width, ambiguous);
             (suffix, 0)
         }
         SuffixLimit::Replace(c) => {
@@ -271,7 +350,12 @@ where
             try_color: s.try_color,
         });
 
-        let widths = truncate_total_width(records, cfg, widths, total, width, P::create(), suffix);
+        let mut truncate = Truncate::new(0);
+        truncate.suffix = suffix;
+        truncate.position = self.position;
+
+        let widths =
+            truncate_total_width(records, cfg, widths, total, width, P::create(), truncate);
 
         let _ = dims.set_widths(widths);
     }
@@ -284,7 +368,7 @@ fn truncate_total_width<P: Peaker, R: Records + ExactRecords + RecordsMut<String
     total: usize,
     width: usize,
     priority: P,
-    suffix: Option<TruncateSuffix<'_>>,
+    mut truncate: Truncate<'_>,
 ) -> Vec<usize>
 where
     for<'a> &'a R: Records,
@@ -298,8 +382,6 @@ where
 
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, (count_rows, count_columns));
 
-    let mut truncate = Truncate::new(0);
-    truncate.suffix = suffix;
     for ((row, col), width) in points {
         truncate.width = width;
         CellOption::change(&mut truncate, records, cfg, (row, col).into());
@@ -312,50 +394,78 @@ fn truncate_text<'a>(
     content: &'a str,
     width: usize,
     suffix: &str,
-    _suffix_color_try_keeping: bool,
+    suffix_color_try_keeping: bool,
+    ambiguous: AmbiguousWidth,
 ) -> Cow<'a, str> {
-    let content = cut_str(content, width);
+    let content = cut_str(content, width, ambiguous);
 
     if suffix.is_empty() {
         return content;
     }
 
+    let suffix = color_suffix(suffix, &content, false, suffix_color_try_keeping);
+
+    let mut content = content.into_owned();
+    content.push_str(&suffix);
+    Cow::Owned(content)
+}
+
+/// Cuts a line at a given position keeping `width` of it and puts a suffix at the cut point.
+fn truncate_line(
+    line: &str,
+    width: usize,
+    suffix: &str,
+    position: TruncatePosition,
+    suffix_color_try_keeping: bool,
+    ambiguous: AmbiguousWidth,
+) -> String {
+    match position {
+        TruncatePosition::Start => {
+            let right = cut_str_start(line, width, ambiguous);
+            let suffix = color_suffix(suffix, &right, true, suffix_color_try_keeping);
+            format!("{}{}", suffix, right)
+        }
+        TruncatePosition::Middle => {
+            let left = cut_str(line, width - width / 2, ambiguous);
+            let right = cut_str_start(line, width / 2, ambiguous);
+            let suffix = color_suffix(suffix, &left, false, suffix_color_try_keeping);
+            format!("{}{}{}", left, suffix, right)
+        }
+        TruncatePosition::End => {
+            truncate_text(line, width, suffix, suffix_color_try_keeping, ambiguous).into_owned()
+        }
+    }
+}
+
+/// Colors a suffix the same way as an adjacent part of a text is colored.
+///
+/// The style is taken from the first block of the text if the suffix goes before it,
+/// and from the last one otherwise.
+fn color_suffix<'a>(
+    suffix: &'a str,
+    _text: &str,
+    _is_prefix: bool,
+    _try_keeping: bool,
+) -> Cow<'a, str> {
     #[cfg(feature = "color")]
     {
-        if _suffix_color_try_keeping {
-            if let Some(block) = ansi_str::get_blocks(&content).last() {
+        if _try_keeping {
+            let block = if _is_prefix {
+                ansi_str::get_blocks(_text).next()
+            } else {
+                ansi_str::get_blocks(_text).last()
+            };
+
+            if let Some(block) = block {
                 if block.has_ansi() {
                     let style = block.style();
-                    Cow::Owned(format!(
-                        "{}{}{}{}",
-                        content,
-                        style.start(),
-                        suffix,
-                        style.end()
-                    ))
-                } else {
-                    let mut content = content.into_owned();
-                    content.push_str(suffix);
-                    Cow::Owned(content)
+                    return Cow::Owned(format!("{}{}{}", style.start(), suffix, style.end()));
                 }
-            } else {
-                let mut content = content.into_owned();
-                content.push_str(suffix);
-                Cow::Owned(content)
             }
-        } else {
-            let mut content = content.into_owned();
-            content.push_str(suffix);
-            Cow::Owned(content)
         }
     }
 
-    #[cfg(not(feature = "color"))]
-    {
-        let mut content = content.into_owned();
-        content.push_str(suffix);
-        Cow::Owned(content)
-    }
+    Cow::Borrowed(suffix)
 }
 
 fn get_decrease_cell_list(
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 
 use crate::{
-    grid::spanned::{ExactDimension, GridConfig},
+    grid::{
+        spanned::{ExactDimension, GridConfig},
+        util::string::{graphemes_ambiguous, string_width_ambiguous, AmbiguousWidth},
+    },
     records::Records,
 };
 
@@ -26,21 +29,34 @@ fn get_table_total_width(list: &[usize], cfg: &GridConfig) -> usize {
         + margin.right.indent.size
 }
 
+/// Returns a char which replaces a part of a wide character which was split.
+///
+/// `U+FFFD` is an East Asian ambiguous character itself,
+/// so a space is used instead in case ambiguous characters are wide.
+pub(crate) fn replacement_char(ambiguous: AmbiguousWidth) -> char {
+    const REPLACEMENT: char = '\u{FFFD}';
+
+    match ambiguous {
+        AmbiguousWidth::Narrow => REPLACEMENT,
+        AmbiguousWidth::Wide => ' ',
+    }
+}
+
 /// The function cuts the string to a specific width.
 ///
 /// BE AWARE: width is expected to be in bytes.
-pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
+pub(crate) fn cut_str(s: &str, width: usize, ambiguous: AmbiguousWidth) -> Cow<'_, str> {
     #[cfg(feature = "color")]
     {
-        const REPLACEMENT: char = '\u{FFFD}';
+        let replacement = replacement_char(ambiguous);
 
         let stripped = ansi_str::AnsiStr::ansi_strip(s);
-        let (length, count_unknowns, _) = split_at_pos(&stripped, width);
+        let (length, count_unknowns, _) = split_at_pos(&stripped, width, ambiguous);
 
         let mut buf = ansi_str::AnsiStr::ansi_cut(s, ..length);
         if count_unknowns > 0 {
             let mut b = buf.into_owned();
-            b.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            b.extend(std::iter::repeat(replacement).take(count_unknowns));
             buf = Cow::Owned(b);
         }
 
@@ -48,25 +64,79 @@ pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
     }
     #[cfg(not(feature = "color"))]
     {
-        cut_str_basic(s, width)
+        cut_str_basic(s, width, ambiguous)
+    }
+}
+
+/// The function cuts the beginning of the string, so only a specific width of its end is left.
+pub(crate) fn cut_str_start(s: &str, width: usize, ambiguous: AmbiguousWidth) -> Cow<'_, str> {
+    #[cfg(feature = "color")]
+    let (pos, count_unknowns) = {
+        let stripped = ansi_str::AnsiStr::ansi_strip(s);
+        split_at_pos_from_end(&stripped, width, ambiguous)
+    };
+    #[cfg(not(feature = "color"))]
+    let (pos, count_unknowns) = split_at_pos_from_end(s, width, ambiguous);
+
+    #[cfg(feature = "color")]
+    let buf = ansi_str::AnsiStr::ansi_cut(s, pos..);
+    #[cfg(not(feature = "color"))]
+    let buf = Cow::Borrowed(&s[pos..]);
+
+    if count_unknowns == 0 {
+        return buf;
+    }
+
+    let mut b = std::iter::repeat(replacement_char(ambiguous))
+        .take(count_unknowns)
+        .collect::<String>();
+    b.push_str(&buf);
+
+    Cow::Owned(b)
+}
+
+/// The function returns a position in bytes from which the string has a specific width,
+/// and in case of a split in an unicode grapheme a width which is left from it.
+fn split_at_pos_from_end(s: &str, width: usize, ambiguous: AmbiguousWidth) -> (usize, usize) {
+    let total = string_width_ambiguous(s, ambiguous);
+    if total <= width {
+        return (0, 0);
     }
+
+    let mut skip = total - width;
+    let mut pos = 0;
+    for (c, c_width) in graphemes_ambiguous(s, ambiguous) {
+        if skip == 0 {
+            break;
+        }
+
+        pos += c.len();
+
+        if c_width > skip {
+            return (pos, c_width - skip);
+        }
+
+        skip -= c_width;
+    }
+
+    (pos, 0)
 }
 
 /// The function cuts the string to a specific width.
 ///
 /// BE AWARE: width is expected to be in bytes.
 #[cfg(not(feature = "color"))]
-pub(crate) fn cut_str_basic(s: &str, width: usize) -> Cow<'_, str> {
-    const REPLACEMENT: char = '\u{FFFD}';
+pub(crate) fn cut_str_basic(s: &str, width: usize, ambiguous: AmbiguousWidth) -> Cow<'_, str> {
+    let replacement = replacement_char(ambiguous);
 
-    let (length, count_unknowns, _) = split_at_pos(s, width);
+    let (length, count_unknowns, _) = split_at_pos(s, width, ambiguous);
     let buf = &s[..length];
     if count_unknowns == 0 {
         return Cow::Borrowed(buf);
     }
 
     let mut buf = buf.to_owned();
-    buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+    buf.extend(std::iter::repeat(replacement).take(count_unknowns));
 
     Cow::Owned(buf)
 }
@@ -76,25 +146,27 @@ pub(crate) fn cut_str_basic(s: &str, width: usize) -> Cow<'_, str> {
 /// a width of a character which was tried to be splited in.
 ///
 /// BE AWARE: pos is expected to be in bytes.
-pub(crate) fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
+pub(crate) fn split_at_pos(
+    s: &str,
+    pos: usize,
+    ambiguous: AmbiguousWidth,
+) -> (usize, usize, usize) {
     let mut length = 0;
     let mut i = 0;
-    for c in s.chars() {
+    for (c, c_width) in graphemes_ambiguous(s, ambiguous) {
         if i == pos {
             break;
         };
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
-
         // We cut the chars which takes more then 1 symbol to display,
         // in order to archive the necessary width.
         if i + c_width > pos {
             let count = pos - i;
-            return (length, count, c.len_utf8());
+            return (length, count, c.len());
         }
 
         i += c_width;
-        length += c.len_utf8();
+        length += c.len();
     }
 
     (length, 0, 0)
@@ -108,15 +180,6 @@ pub(crate) fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
 /// The function is based on Dan Davison <https://github.com/dandavison> delta <https://github.com/dandavison/delta> ansi library.
 #[cfg(feature = "color")]
 pub(crate) fn strip_osc(text: &str) -> (String, Option<String>) {
-    #[derive(Debug)]
-    enum ExtractOsc8HyperlinkState {
-        ExpectOsc8Url,
-        ExpectFirstText,
-        ExpectMoreTextOrTerminator,
-        SeenOneHyperlink,
-        WillNotReturnUrl,
-    }
-
     use ExtractOsc8HyperlinkState::*;
 
     let mut url = None;
@@ -125,44 +188,143 @@ pub(crate) fn strip_osc(text: &str) -> (String, Option<String>) {
 
     for el in ansitok::parse_ansi(text) {
         match el.kind() {
-            ansitok::ElementKind::Osc => match state {
-                ExpectOsc8Url => {
-                    url = Some(&text[el.start()..el.end()]);
-                    state = ExpectFirstText;
-                }
-                ExpectMoreTextOrTerminator => state = SeenOneHyperlink,
-                _ => state = WillNotReturnUrl,
-            },
+            ansitok::ElementKind::Osc => {
+                state = state.next_osc(&mut url, &text[el.start()..el.end()]);
+            }
             ansitok::ElementKind::Sgr => buf.push_str(&text[el.start()..el.end()]),
             ansitok::ElementKind::Csi => buf.push_str(&text[el.start()..el.end()]),
             ansitok::ElementKind::Esc => {}
             ansitok::ElementKind::Text => {
                 buf.push_str(&text[el.start()..el.end()]);
-                match state {
-                    ExpectFirstText => state = ExpectMoreTextOrTerminator,
-                    ExpectMoreTextOrTerminator => {}
-                    _ => state = WillNotReturnUrl,
-                }
+                state = state.next_text();
             }
         }
     }
 
-    match state {
-        WillNotReturnUrl => (buf, None),
-        _ => {
-            let url = url.and_then(|s| {
-                s.strip_prefix("\x1b]8;;")
-                    .and_then(|s| s.strip_suffix('\x1b'))
-            });
-            if let Some(url) = url {
-                (buf, Some(url.to_string()))
-            } else {
-                (buf, None)
+    let url = url.and_then(|s| {
+        s.strip_prefix("\x1b]8;;")
+            .and_then(|s| s.strip_suffix('\x1b'))
+    });
+
+    state.finish(buf, url)
+}
+
+/// Strip OSC codes from `s`. If `s` is a single OSC8 hyperlink, with no other text, then return
+/// (s_with_all_hyperlinks_removed, Some(url)). If `s` does not meet this description, then return
+/// (s_with_all_hyperlinks_removed, None). See
+/// <https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda>
+#[cfg(not(feature = "color"))]
+pub(crate) fn strip_osc(text: &str) -> (String, Option<String>) {
+    use ExtractOsc8HyperlinkState::*;
+
+    let mut url = None;
+    let mut state = ExpectOsc8Url;
+    let mut buf = String::with_capacity(text.len());
+
+    let mut rest = text;
+    while !rest.is_empty() {
+        let (chunk, osc, tail) = match find_osc(rest) {
+            Some((start, end)) => (&rest[..start], Some(&rest[start..end]), &rest[end..]),
+            None => (rest, None, ""),
+        };
+
+        if !chunk.is_empty() {
+            buf.push_str(chunk);
+            state = state.next_text();
+        }
+
+        if let Some(osc) = osc {
+            state = state.next_osc(&mut url, osc);
+        }
+
+        rest = tail;
+    }
+
+    let url = url.and_then(|s| {
+        s.strip_prefix("\x1b]8;;")
+            .and_then(|s| s.strip_suffix("\x1b\\").or_else(|| s.strip_suffix('\u{7}')))
+    });
+
+    state.finish(buf, url)
+}
+
+/// Finds a first OSC sequence in a text, which is terminated by either `ST` or `BEL`.
+#[cfg(not(feature = "color"))]
+fn find_osc(text: &str) -> Option<(usize, usize)> {
+    let start = text.find("\x1b]")?;
+    let end = crate::grid::util::string::find_osc_end(&text[start + 2..])?;
+
+    Some((start, start + 2 + end))
+}
+
+#[derive(Debug)]
+enum ExtractOsc8HyperlinkState {
+    ExpectOsc8Url,
+    ExpectFirstText,
+    ExpectMoreTextOrTerminator,
+    SeenOneHyperlink,
+    WillNotReturnUrl,
+}
+
+impl ExtractOsc8HyperlinkState {
+    fn next_osc<'a>(self, url: &mut Option<&'a str>, osc: &'a str) -> Self {
+        use ExtractOsc8HyperlinkState::*;
+
+        match self {
+            ExpectOsc8Url => {
+                *url = Some(osc);
+                ExpectFirstText
             }
+            ExpectMoreTextOrTerminator => SeenOneHyperlink,
+            _ => WillNotReturnUrl,
+        }
+    }
+
+    fn next_text(self) -> Self {
+        use ExtractOsc8HyperlinkState::*;
+
+        match self {
+            ExpectFirstText | ExpectMoreTextOrTerminator => ExpectMoreTextOrTerminator,
+            _ => WillNotReturnUrl,
+        }
+    }
+
+    fn finish(self, buf: String, url: Option<&str>) -> (String, Option<String>) {
+        match (self, url) {
+            (ExtractOsc8HyperlinkState::WillNotReturnUrl, _) | (_, None) => (buf, None),
+            (_, Some(url)) => (buf, Some(url.to_string())),
         }
     }
 }
 
+/// Builds an OSC 8 prefix and suffix for a hyperlink (or empty strings if there's no url).
+pub(crate) fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
+    match url {
+        Some(url) => {
+            // https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+            let osc8 = "\x1b]8;;";
+            let st = "\x1b\\";
+
+            (format!("{osc8}{url}{st}"), format!("{osc8}{st}"))
+        }
+        None => ("".to_string(), "".to_string()),
+    }
+}
+
+/// Wraps each line of a text into a hyperlink if there's one.
+pub(crate) fn restore_link(text: String, url: Option<String>) -> String {
+    if url.is_none() {
+        return text;
+    }
+
+    let (prefix, suffix) = build_link_prefix_suffix(url);
+
+    text.split('\n')
+        .map(|line| format!("{}{}{}", prefix, line, suffix))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +334,10 @@ mod tests {
     #[cfg(feature = "color")]
     use owo_colors::{colors::Yellow, OwoColorize};
 
+    fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
+        super::cut_str(s, width, AmbiguousWidth::Narrow)
+    }
+
     #[test]
     fn strip_test() {
         assert_eq!(cut_str("123456", 0), "");
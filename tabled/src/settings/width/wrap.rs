@@ -6,7 +6,14 @@
 use std::marker::PhantomData;
 
 use crate::{
-    grid::{config::Entity, spanned::GridConfig, util::string::string_width_multiline},
+    grid::{
+        config::Entity,
+        spanned::GridConfig,
+        util::string::{
+            get_lines, graphemes_ambiguous, string_width_ambiguous,
+            string_width_multiline_ambiguous, AmbiguousWidth,
+        },
+    },
     records::ExactRecords,
     records::{EmptyRecords, Records, RecordsMut},
     settings::{
@@ -18,7 +25,10 @@ use crate::{
     tables::table::{ColoredConfig, TableDimension},
 };
 
-use super::util::{get_table_widths, get_table_widths_with_total, split_at_pos};
+use super::util::{
+    build_link_prefix_suffix, get_table_widths, get_table_widths_with_total, replacement_char,
+    split_at_pos, strip_osc,
+};
 
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
 /// Otherwise keeps the content of a cell untouched.
@@ -41,10 +51,39 @@ use super::util::{get_table_widths, get_table_widths_with_total, split_at_pos};
 #[derive(Debug, Clone)]
 pub struct Wrap<W = usize, P = PriorityNone> {
     width: W,
-    keep_words: bool,
+    mode: WrapMode,
     _priority: PhantomData<P>,
 }
 
+/// A set of rules used to find line breaks.
+#[derive(Debug, Clone, Copy)]
+struct WrapMode {
+    keep_words: bool,
+    punctuation: bool,
+    hyphenate: bool,
+    balance: bool,
+    /// A width of East Asian ambiguous characters taken from a table config.
+    ambiguous: AmbiguousWidth,
+}
+
+impl Default for WrapMode {
+    fn default() -> Self {
+        Self {
+            keep_words: false,
+            punctuation: false,
+            hyphenate: false,
+            balance: false,
+            ambiguous: AmbiguousWidth::Narrow,
+        }
+    }
+}
+
+impl WrapMode {
+    fn is_extended(&self) -> bool {
+        self.punctuation || self.hyphenate || self.balance
+    }
+}
+
 impl<W> Wrap<W> {
     /// Creates a [`Wrap`] object
     pub fn new(width: W) -> Self
@@ -53,7 +92,7 @@ impl<W> Wrap<W> {
     {
         Wrap {
             width,
-            keep_words: false,
+            mode: WrapMode::default(),
             _priority: PhantomData::default(),
         }
     }
@@ -75,7 +114,7 @@ impl<W, P> Wrap<W, P> {
     pub fn priority<PP>(self) -> Wrap<W, PP> {
         Wrap {
             width: self.width,
-            keep_words: self.keep_words,
+            mode: self.mode,
             _priority: PhantomData::default(),
         }
     }
@@ -85,7 +124,57 @@ impl<W, P> Wrap<W, P> {
     /// If a wrapping point will be in a word, [`Wrap`] will
     /// preserve a word (if possible) and wrap the string before it.
     pub fn keep_words(mut self) -> Self {
-        self.keep_words = true;
+        self.mode.keep_words = true;
+        self
+    }
+
+    /// Allows to break a line after `-`, `/`, `_` and `.` characters in addition to spaces,
+    /// so paths and URLs are wrapped at their boundaries.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Rows, width::Width, Modify}};
+    ///
+    /// let table = Table::new(["/usr/local/bin"])
+    ///     .with(Modify::new(Rows::new(1..)).with(Width::wrap(8).break_on_punctuation()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+\n\
+    ///      | &str   |\n\
+    ///      +--------+\n\
+    ///      | /usr/  |\n\
+    ///      | local/ |\n\
+    ///      | bin    |\n\
+    ///      +--------+"
+    /// );
+    /// ```
+    pub fn break_on_punctuation(mut self) -> Self {
+        self.mode.keep_words = true;
+        self.mode.punctuation = true;
+        self
+    }
+
+    /// Inserts a hyphen (`-`) at points where a word is too long and has to be split.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    pub fn hyphenate(mut self) -> Self {
+        self.mode.keep_words = true;
+        self.mode.hyphenate = true;
+        self
+    }
+
+    /// Balances lengths of lines instead of filling each line as much as possible.
+    ///
+    /// Line breaks are chosen so the sum of squared gaps at the end of lines is minimal
+    /// (like Knuth–Plass algorithm does), which gives less ragged lines.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    pub fn balance(mut self) -> Self {
+        self.mode.keep_words = true;
+        self.mode.balance = true;
         self
     }
 }
@@ -114,8 +203,8 @@ where
         }
 
         let priority = P::create();
-        let keep_words = self.keep_words;
-        let widths = wrap_total_width(records, cfg, widths, total, width, keep_words, priority);
+        let mode = self.mode;
+        let widths = wrap_total_width(records, cfg, widths, total, width, mode, priority);
 
         let _ = dims.set_widths(widths);
     }
@@ -129,18 +218,22 @@ where
 {
     fn change(&mut self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
         let width = self.width.measure(&*records, cfg);
+        let mode = WrapMode {
+            ambiguous: cfg.get_ambiguous_width(),
+            ..self.mode
+        };
 
         let count_rows = records.count_rows();
         let count_columns = records.count_columns();
 
         for pos in entity.iter(count_rows, count_columns) {
             let text = records.get_cell(pos).as_ref();
-            let cell_width = string_width_multiline(text);
+            let cell_width = string_width_multiline_ambiguous(text, mode.ambiguous);
             if cell_width <= width {
                 continue;
             }
 
-            let wrapped = wrap_text(text, width, self.keep_words);
+            let wrapped = wrap_text_with_mode(text, width, mode);
             records.set(pos, wrapped);
         }
     }
@@ -152,7 +245,7 @@ fn wrap_total_width<R, P>(
     mut widths: Vec<usize>,
     total_width: usize,
     width: usize,
-    keep_words: bool,
+    mode: WrapMode,
     priority: P,
 ) -> Vec<usize>
 where
@@ -168,7 +261,7 @@ where
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, shape);
 
     let mut wrap = Wrap::new(0);
-    wrap.keep_words = keep_words;
+    wrap.mode = mode;
     for ((row, col), width) in points {
         wrap.width = width;
         <Wrap as CellOption<R, ColoredConfig>>::change(&mut wrap, records, cfg, (row, col).into());
@@ -177,23 +270,43 @@ where
     widths
 }
 
+fn wrap_text_with_mode(text: &str, width: usize, mode: WrapMode) -> String {
+    if mode.is_extended() {
+        wrap_words(text, width, mode)
+    } else {
+        wrap_text(text, width, mode.keep_words, mode.ambiguous)
+    }
+}
+
 #[cfg(not(feature = "color"))]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    ambiguous: AmbiguousWidth,
+) -> String {
     if width == 0 {
         return String::new();
     }
 
-    if keep_words {
-        split_keeping_words(text, width, "\n")
+    let (text, url) = strip_osc(text);
+
+    let text = if keep_words {
+        split_keeping_words(&text, width, "\n", ambiguous)
     } else {
-        chunks(text, width).join("\n")
-    }
+        chunks(&text, width, ambiguous).join("\n")
+    };
+
+    super::util::restore_link(text, url)
 }
 
 #[cfg(feature = "color")]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
-    use super::util::strip_osc;
-
+pub(crate) fn wrap_text(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    ambiguous: AmbiguousWidth,
+) -> String {
     if width == 0 {
         return String::new();
     }
@@ -202,45 +315,30 @@ pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
     let (prefix, suffix) = build_link_prefix_suffix(url);
 
     if keep_words {
-        split_keeping_words(&text, width, &prefix, &suffix)
+        split_keeping_words(&text, width, &prefix, &suffix, ambiguous)
     } else {
-        chunks(&text, width, &prefix, &suffix).join("\n")
-    }
-}
-
-#[cfg(feature = "color")]
-fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
-    match url {
-        Some(url) => {
-            // https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
-            let osc8 = "\x1b]8;;";
-            let st = "\x1b\\";
-
-            (format!("{osc8}{url}{st}"), format!("{osc8}{st}"))
-        }
-        None => ("".to_string(), "".to_string()),
+        chunks(&text, width, &prefix, &suffix, ambiguous).join("\n")
     }
 }
 
 #[cfg(not(feature = "color"))]
-fn chunks(s: &str, width: usize) -> Vec<String> {
+fn chunks(s: &str, width: usize, ambiguous: AmbiguousWidth) -> Vec<String> {
     if width == 0 {
         return Vec::new();
     }
 
-    const REPLACEMENT: char = '\u{FFFD}';
+    let replacement = replacement_char(ambiguous);
 
     let mut buf = String::with_capacity(width);
     let mut list = Vec::new();
     let mut i = 0;
-    for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
+    for (c, c_width) in graphemes_ambiguous(s, ambiguous) {
         if i + c_width > width {
             let count_unknowns = width - i;
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            buf.extend(std::iter::repeat(replacement).take(count_unknowns));
             i += count_unknowns;
         } else {
-            buf.push(c);
+            buf.push_str(c);
             i += c_width;
         }
 
@@ -259,7 +357,13 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
 }
 
 #[cfg(feature = "color")]
-fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+fn chunks(
+    s: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    ambiguous: AmbiguousWidth,
+) -> Vec<String> {
     use std::fmt::Write;
 
     if width == 0 {
@@ -285,7 +389,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
         while !part.is_empty() {
             let available_space = width - line_width;
 
-            let part_width = unicode_width::UnicodeWidthStr::width(part);
+            let part_width = string_width_ambiguous(part, ambiguous);
             if part_width <= available_space {
                 line.push_str(part);
                 line_width += part_width;
@@ -303,15 +407,16 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
                 break;
             }
 
-            let (lhs, rhs, (unknowns, split_char)) = split_string_at(part, available_space);
+            let (lhs, rhs, (unknowns, split_char)) =
+                split_string_at(part, available_space, ambiguous);
 
             part = &rhs[split_char..];
 
             line.push_str(lhs);
-            line_width += unicode_width::UnicodeWidthStr::width(lhs);
+            line_width += string_width_ambiguous(lhs, ambiguous);
 
-            const REPLACEMENT: char = '\u{FFFD}';
-            line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+            let replacement = replacement_char(ambiguous);
+            line.extend(std::iter::repeat(replacement).take(unknowns));
             line_width += unknowns;
 
             if line_width == width {
@@ -339,8 +444,8 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
 }
 
 #[cfg(not(feature = "color"))]
-fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
-    const REPLACEMENT: char = '\u{FFFD}';
+fn split_keeping_words(s: &str, width: usize, sep: &str, ambiguous: AmbiguousWidth) -> String {
+    let replacement = replacement_char(ambiguous);
 
     let mut lines = Vec::new();
     let mut line = String::with_capacity(width);
@@ -362,7 +467,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             is_first_word = false;
         }
 
-        let word_width = unicode_width::UnicodeWidthStr::width(word);
+        let word_width = string_width_ambiguous(word, ambiguous);
 
         let line_has_space = line_width + word_width <= width;
         if line_has_space {
@@ -390,14 +495,14 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             while !word_part.is_empty() {
                 let available_space = width - line_width;
                 let (lhs, rhs, (unknowns, split_char)) =
-                    split_string_at(word_part, available_space);
+                    split_string_at(word_part, available_space, ambiguous);
 
                 word_part = &rhs[split_char..];
-                line_width += unicode_width::UnicodeWidthStr::width(lhs) + unknowns;
+                line_width += string_width_ambiguous(lhs, ambiguous) + unknowns;
                 is_first_word = false;
 
                 line.push_str(lhs);
-                line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+                line.extend(std::iter::repeat(replacement).take(unknowns));
 
                 if line_width == width {
                     lines.push(line);
@@ -418,7 +523,13 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
 }
 
 #[cfg(feature = "color")]
-fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) -> String {
+fn split_keeping_words(
+    text: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    ambiguous: AmbiguousWidth,
+) -> String {
     use std::fmt::Write;
 
     use ansi_str::Style;
@@ -432,6 +543,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     let mut word_begin_pos = 0;
     let mut word_length = 0;
     let mut is_empty_buf = true;
+    let mut is_prev_space = false;
 
     let split = |buf: &mut String, style: &Style| {
         let _ = write!(buf, "{}", style.end());
@@ -454,11 +566,10 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
 
         let _ = write!(buf, "{}", style.start());
 
-        for c in block.text().chars() {
-            let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
+        for (c, c_width) in graphemes_ambiguous(block.text(), ambiguous) {
             let is_enough_space = line_width + c_width <= width;
 
-            let is_space = c == ' ';
+            let is_space = c == " ";
             if is_space {
                 word_length = 0;
                 word_begin_pos = 0;
@@ -466,9 +577,17 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                 if !is_enough_space {
                     split(&mut buf, &style);
                     line_width = 0;
+
+                    // a space which separates words is dropped at a line break
+                    if !is_prev_space {
+                        is_prev_space = true;
+                        continue;
+                    }
                 }
 
-                buf.push(c);
+                is_prev_space = true;
+
+                buf.push_str(c);
                 line_width += 1;
 
                 if is_empty_buf {
@@ -477,13 +596,15 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                 continue;
             }
 
+            is_prev_space = false;
+
             let is_first_c = word_length == 0;
             if is_first_c {
                 word_begin_pos = buf.len();
             }
 
             if is_enough_space {
-                buf.push(c);
+                buf.push_str(c);
                 word_length += c_width;
                 line_width += c_width;
 
@@ -498,14 +619,22 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                 if is_word_small {
                     // move it to other line
 
-                    if !is_empty_buf {
+                    let line_start = buf[..word_begin_pos].rfind('\n').map_or(0, |i| i + 1);
+                    let is_space_line = is_space_only(&buf[line_start..word_begin_pos]);
+                    if is_space_line {
+                        // a line would have only styles and spaces, so the word is kept on it
+                        // instead of starting a new line.
+
+                        let line = buf[line_start..word_begin_pos].replace(' ', "");
+                        buf.replace_range(line_start..word_begin_pos, &line);
+                    } else if !is_empty_buf {
                         // we don't fill the rest of the prev line here
 
                         let sep = format!("{}{}\n{}{}", style.end(), suffix, prefix, style.start());
                         buf.insert_str(word_begin_pos, &sep);
                     }
 
-                    buf.push(c);
+                    buf.push_str(c);
                     line_width = partial_word_width;
                     word_length += c_width;
 
@@ -521,12 +650,12 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
 
                     let is_big_char = c_width > width;
                     if is_big_char {
-                        const REPLACEMENT: char = '\u{FFFD}';
-                        buf.extend(std::iter::repeat(REPLACEMENT).take(width));
+                        let replacement = replacement_char(ambiguous);
+                        buf.extend(std::iter::repeat(replacement).take(width));
                         line_width = width;
                         word_length = width;
                     } else {
-                        buf.push(c);
+                        buf.push_str(c);
                         line_width = c_width;
                         word_length += c_width;
                     }
@@ -541,6 +670,15 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
         let _ = write!(buf, "{}", style.end());
     }
 
+    // a last line which has only a prefix and styles (e.g. a hyperlink wrapper) is dropped,
+    // because its previous line is already closed.
+    if line_width == 0 {
+        if let Some(pos) = buf.rfind('\n') {
+            buf.truncate(pos);
+            return buf;
+        }
+    }
+
     if line_width > 0 {
         buf.push_str(suffix);
     }
@@ -554,8 +692,322 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     buf
 }
 
-fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
-    let (length, count_unknowns, split_char_size) = split_at_pos(text, at);
+/// Checks whether a part of a line has spaces and nothing else except ANSI sequences.
+#[cfg(feature = "color")]
+fn is_space_only(text: &str) -> bool {
+    let text = ansi_str::AnsiStr::ansi_strip(text);
+    !text.is_empty() && text.chars().all(|c| c == ' ')
+}
+
+/// A part of a line which is not split,
+/// with positions in bytes of a line without ANSI sequences.
+#[derive(Debug, Clone, Copy)]
+struct Word {
+    start: usize,
+    end: usize,
+    width: usize,
+    /// A width of a space after the word which is dropped in case of a line break.
+    space: usize,
+    /// Whether a hyphen is added in case of a line break after the word.
+    hyphen: bool,
+    /// Whether the word is a character which is wider than a line,
+    /// so it's replaced by replacement characters.
+    replace: bool,
+}
+
+/// Wraps a text by words taking into account [`WrapMode`].
+fn wrap_words(text: &str, width: usize, mode: WrapMode) -> String {
+    if width == 0 {
+        return String::new();
+    }
+
+    let (text, url) = strip_osc(text);
+    let (prefix, suffix) = build_link_prefix_suffix(url);
+    let text = text.as_str();
+    let replacement = replacement_char(mode.ambiguous);
+
+    let mut lines = Vec::new();
+    for line in get_lines(text) {
+        #[cfg(not(feature = "color"))]
+        let stripped = line.as_ref();
+        #[cfg(feature = "color")]
+        let stripped = ansi_str::AnsiStr::ansi_strip(line.as_ref());
+        #[cfg(feature = "color")]
+        let stripped = stripped.as_ref();
+
+        let words = split_words(stripped, width, mode);
+        let breaks = if mode.balance {
+            find_balanced_breaks(&words, width)
+        } else {
+            find_greedy_breaks(&words, width)
+        };
+
+        let mut start = 0;
+        for end in breaks {
+            let words = &words[start..end];
+            start = end;
+
+            #[cfg(not(feature = "color"))]
+            let text = build_line(words, replacement, |start, end| &line[start..end]);
+
+            #[cfg(feature = "color")]
+            let text = build_line(words, replacement, |start, end| {
+                ansi_str::AnsiStr::ansi_cut(line.as_ref(), start..end)
+            });
+
+            lines.push(format!("{}{}{}", prefix, text, suffix));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn split_words(line: &str, width: usize, mode: WrapMode) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut pos = 0;
+    let mut word_start = 0;
+    let mut word_width = 0;
+
+    for (c, c_width) in graphemes_ambiguous(line, mode.ambiguous) {
+        if c == " " {
+            push_word(
+                &mut words,
+                line,
+                (word_start, pos),
+                word_width,
+                1,
+                width,
+                mode,
+            );
+            pos += c.len();
+            word_start = pos;
+            word_width = 0;
+            continue;
+        }
+
+        pos += c.len();
+        word_width += c_width;
+
+        let is_breakpoint = matches!(c, "-" | "/" | "_" | ".");
+        if mode.punctuation && is_breakpoint {
+            push_word(
+                &mut words,
+                line,
+                (word_start, pos),
+                word_width,
+                0,
+                width,
+                mode,
+            );
+            word_start = pos;
+            word_width = 0;
+        }
+    }
+
+    push_word(
+        &mut words,
+        line,
+        (word_start, pos),
+        word_width,
+        0,
+        width,
+        mode,
+    );
+
+    words
+}
+
+/// Adds a word to a list, splitting it in case it's longer than a line.
+fn push_word(
+    words: &mut Vec<Word>,
+    line: &str,
+    (start, end): (usize, usize),
+    width: usize,
+    space: usize,
+    max_width: usize,
+    mode: WrapMode,
+) {
+    if width <= max_width {
+        let word = Word {
+            start,
+            end,
+            width,
+            space,
+            hyphen: false,
+            replace: false,
+        };
+        words.push(word);
+        return;
+    }
+
+    let hyphen = mode.hyphenate && max_width > 1;
+    let limit = if hyphen { max_width - 1 } else { max_width };
+
+    let mut chunk_start = start;
+    let mut chunk_width = 0;
+    let mut pos = start;
+    for (c, c_width) in graphemes_ambiguous(&line[start..end], mode.ambiguous) {
+        if chunk_width > 0 && chunk_width + c_width > limit {
+            words.push(Word {
+                start: chunk_start,
+                end: pos,
+                width: chunk_width,
+                space: 0,
+                hyphen,
+                replace: false,
+            });
+            chunk_start = pos;
+            chunk_width = 0;
+        }
+
+        if chunk_width == 0 && c_width > limit {
+            // a character which doesn't fit along with a hyphen
+            let replace = c_width > max_width;
+            words.push(Word {
+                start: pos,
+                end: pos + c.len(),
+                width: if replace { max_width } else { c_width },
+                space: 0,
+                hyphen: false,
+                replace,
+            });
+
+            pos += c.len();
+            chunk_start = pos;
+            continue;
+        }
+
+        pos += c.len();
+        chunk_width += c_width;
+    }
+
+    if chunk_start < end {
+        words.push(Word {
+            start: chunk_start,
+            end,
+            width: chunk_width,
+            space,
+            hyphen: false,
+            replace: false,
+        });
+    } else if let Some(last) = words.last_mut() {
+        last.space = space;
+    }
+}
+
+/// Returns a width of a line which ends with a given word,
+/// having a width of the rest of words of the line, including spaces after them.
+fn line_width(rest: usize, last: &Word) -> usize {
+    rest + last.width + usize::from(last.hyphen)
+}
+
+/// Returns ends of lines filling each line as much as possible.
+fn find_greedy_breaks(words: &[Word], width: usize) -> Vec<usize> {
+    let mut breaks = Vec::new();
+    let mut start = 0;
+    while start < words.len() {
+        let mut rest = words[start].width + words[start].space;
+        let mut end = start + 1;
+        while end < words.len() && line_width(rest, &words[end]) <= width {
+            rest += words[end].width + words[end].space;
+            end += 1;
+        }
+
+        breaks.push(end);
+        start = end;
+    }
+
+    breaks
+}
+
+/// Returns ends of lines which minimize a sum of squared gaps at the end of lines,
+/// except the last line.
+fn find_balanced_breaks(words: &[Word], width: usize) -> Vec<usize> {
+    let count = words.len();
+
+    // a cost of wrapping words starting from an index and an end of its first line.
+    let mut costs = vec![0; count + 1];
+    let mut ends = vec![count; count + 1];
+
+    for start in (0..count).rev() {
+        let mut best = None;
+        let mut rest = 0;
+        for end in start + 1..=count {
+            let last = &words[end - 1];
+            let line_width = line_width(rest, last);
+            rest += last.width + last.space;
+
+            // a first word is kept even if it's too long, yet no more words fit after it.
+            let is_single_word = end == start + 1;
+            if line_width > width && !is_single_word {
+                break;
+            }
+
+            let gap = width.saturating_sub(line_width);
+            let cost = if end == count { 0 } else { gap * gap };
+            let cost = cost + costs[end];
+
+            if best.map_or(true, |best| cost < best) {
+                best = Some(cost);
+                ends[start] = end;
+            }
+        }
+
+        costs[start] = best.unwrap_or(0);
+    }
+
+    let mut breaks = Vec::new();
+    let mut start = 0;
+    while start < count {
+        start = ends[start];
+        breaks.push(start);
+    }
+
+    breaks
+}
+
+fn build_line<F, S>(words: &[Word], replacement: char, cut: F) -> String
+where
+    F: Fn(usize, usize) -> S,
+    S: AsRef<str>,
+{
+    // words are cut by continuous parts, so a style is not reopened for each word.
+    let mut buf = String::new();
+    let mut part_start = None;
+    for (i, word) in words.iter().enumerate() {
+        if !word.replace {
+            let _ = part_start.get_or_insert(word.start);
+            continue;
+        }
+
+        if let Some(start) = part_start.take() {
+            buf.push_str(cut(start, word.start).as_ref());
+        }
+
+        buf.extend(std::iter::repeat(replacement).take(word.width));
+
+        if i + 1 < words.len() {
+            part_start = Some(word.end);
+        }
+    }
+
+    if let (Some(start), Some(last)) = (part_start, words.last()) {
+        buf.push_str(cut(start, last.end).as_ref());
+    }
+
+    if words.last().map_or(false, |word| word.hyphen) {
+        buf.push('-');
+    }
+
+    buf
+}
+
+fn split_string_at(
+    text: &str,
+    at: usize,
+    ambiguous: AmbiguousWidth,
+) -> (&str, &str, (usize, usize)) {
+    let (length, count_unknowns, split_char_size) = split_at_pos(text, at, ambiguous);
     let (lhs, rhs) = text.split_at(length);
 
     (lhs, rhs, (count_unknowns, split_char_size))
@@ -646,6 +1098,26 @@ fn count_borders(cfg: &GridConfig, start: usize, end: usize, count_columns: usiz
 mod tests {
     use super::*;
 
+    #[cfg(not(feature = "color"))]
+    fn chunks(s: &str, width: usize) -> Vec<String> {
+        super::chunks(s, width, AmbiguousWidth::Narrow)
+    }
+
+    #[cfg(feature = "color")]
+    fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+        super::chunks(s, width, prefix, suffix, AmbiguousWidth::Narrow)
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
+        super::split_keeping_words(s, width, sep, AmbiguousWidth::Narrow)
+    }
+
+    #[cfg(feature = "color")]
+    fn split_keeping_words(s: &str, width: usize, prefix: &str, suffix: &str) -> String {
+        super::split_keeping_words(s, width, prefix, suffix, AmbiguousWidth::Narrow)
+    }
+
     #[test]
     fn split_test() {
         #[cfg(not(feature = "color"))]
@@ -732,8 +1204,8 @@ mod tests {
 
         let text = "\u{1b}[36mJapanese “vacancy” button\u{1b}[0m";
 
-        assert_eq!(split_keeping_words(text, 2), "\u{1b}[36mJa\u{1b}[39m\n\u{1b}[36mpa\u{1b}[39m\n\u{1b}[36mne\u{1b}[39m\n\u{1b}[36mse\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36m“v\u{1b}[39m\n\u{1b}[36mac\u{1b}[39m\n\u{1b}[36man\u{1b}[39m\n\u{1b}[36mcy\u{1b}[39m\n\u{1b}[36m” \u{1b}[39m\n\u{1b}[36mbu\u{1b}[39m\n\u{1b}[36mtt\u{1b}[39m\n\u{1b}[36mon\u{1b}[39m");
-        assert_eq!(split_keeping_words(text, 1), "\u{1b}[36mJ\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mp\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36ms\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36m“\u{1b}[39m\n\u{1b}[36mv\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36my\u{1b}[39m\n\u{1b}[36m”\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36mb\u{1b}[39m\n\u{1b}[36mu\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mo\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m");
+        assert_eq!(split_keeping_words(text, 2), "\u{1b}[36mJa\u{1b}[39m\n\u{1b}[36mpa\u{1b}[39m\n\u{1b}[36mne\u{1b}[39m\n\u{1b}[36mse\u{1b}[39m\n\u{1b}[36m“v\u{1b}[39m\n\u{1b}[36mac\u{1b}[39m\n\u{1b}[36man\u{1b}[39m\n\u{1b}[36mcy\u{1b}[39m\n\u{1b}[36m” \u{1b}[39m\n\u{1b}[36mbu\u{1b}[39m\n\u{1b}[36mtt\u{1b}[39m\n\u{1b}[36mon\u{1b}[39m");
+        assert_eq!(split_keeping_words(text, 1), "\u{1b}[36mJ\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mp\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36ms\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36m“\u{1b}[39m\n\u{1b}[36mv\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36my\u{1b}[39m\n\u{1b}[36m”\u{1b}[39m\n\u{1b}[36mb\u{1b}[39m\n\u{1b}[36mu\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mo\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m");
     }
 
     #[cfg(feature = "color")]
@@ -761,16 +1233,12 @@ mod tests {
                 "\u{1b}[37mua\u{1b}[39m",
                 "\u{1b}[37mdo\u{1b}[39m",
                 "\u{1b}[37mr \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mOM\u{1b}[39m",
                 "\u{1b}[37mYA\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mAn\u{1b}[39m",
                 "\u{1b}[37mdi\u{1b}[39m",
                 "\u{1b}[37mna\u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m38\u{1b}[39m",
                 "\u{1b}[37m24\u{1b}[39m",
                 "\u{1b}[37m90\u{1b}[39m",
@@ -778,7 +1246,6 @@ mod tests {
                 "\u{1b}[37m99\u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mCa\u{1b}[39m",
                 "\u{1b}[37mlc\u{1b}[39m",
                 "\u{1b}[37miu\u{1b}[39m",
@@ -790,7 +1257,6 @@ mod tests {
                 "\u{1b}[37me \u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mCo\u{1b}[39m",
                 "\u{1b}[37mlo\u{1b}[39m",
                 "\u{1b}[37mmb\u{1b}[39m",
@@ -808,7 +1274,6 @@ mod tests {
                 "\u{1b}[37mg\u{1b}[39m",
                 "\u{1b}[37mr\u{1b}[39m",
                 "\u{1b}[37me\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mE\u{1b}[39m",
                 "\u{1b}[37mc\u{1b}[39m",
                 "\u{1b}[37mu\u{1b}[39m",
@@ -817,13 +1282,10 @@ mod tests {
                 "\u{1b}[37mo\u{1b}[39m",
                 "\u{1b}[37mr\u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mO\u{1b}[39m",
                 "\u{1b}[37mM\u{1b}[39m",
                 "\u{1b}[37mY\u{1b}[39m",
                 "\u{1b}[37mA\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mA\u{1b}[39m",
                 "\u{1b}[37mn\u{1b}[39m",
                 "\u{1b}[37md\u{1b}[39m",
@@ -833,8 +1295,6 @@ mod tests {
                 "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m3\u{1b}[39m",
                 "\u{1b}[37m8\u{1b}[39m",
                 "\u{1b}[37m2\u{1b}[39m",
@@ -849,8 +1309,6 @@ mod tests {
                 "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mC\u{1b}[39m",
                 "\u{1b}[37ma\u{1b}[39m",
                 "\u{1b}[37ml\u{1b}[39m",
@@ -858,7 +1316,6 @@ mod tests {
                 "\u{1b}[37mi\u{1b}[39m",
                 "\u{1b}[37mu\u{1b}[39m",
                 "\u{1b}[37mm\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mc\u{1b}[39m",
                 "\u{1b}[37ma\u{1b}[39m",
                 "\u{1b}[37mr\u{1b}[39m",
@@ -873,8 +1330,6 @@ mod tests {
                 "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mC\u{1b}[39m",
                 "\u{1b}[37mo\u{1b}[39m",
                 "\u{1b}[37ml\u{1b}[39m",
@@ -905,6 +1360,15 @@ mod tests {
             ),
         );
 
+        #[cfg(feature = "grapheme")]
+        assert_eq!(
+            split_keeping_words(
+                "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
+                3,
+            ),
+            "\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻\u{1b}[39m ",
+        );
+        #[cfg(not(feature = "grapheme"))]
         assert_eq!(
             split_keeping_words(
                 "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
@@ -914,7 +1378,7 @@ mod tests {
         );
         assert_eq!(
             split_keeping_words("\u{1b}[37mthis is a long sentence\u{1b}[0m", 7),
-            "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37m a long\u{1b}[39m\n\u{1b}[37m \u{1b}[39m\n\u{1b}[37msentenc\u{1b}[39m\n\u{1b}[37me\u{1b}[39m      "
+            "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37ma long \u{1b}[39m\n\u{1b}[37msentenc\u{1b}[39m\n\u{1b}[37me\u{1b}[39m      "
         );
         assert_eq!(
             split_keeping_words("\u{1b}[37mHello World\u{1b}[0m", 7),
@@ -1021,13 +1485,11 @@ mod tests {
                 "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37mOM\u{1b}[39m$",
                 "^\u{1b}[37mYA\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mAn\u{1b}[39m$",
                 "^\u{1b}[37mdi\u{1b}[39m$",
                 "^\u{1b}[37mna\u{1b}[39m$",
                 "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m38\u{1b}[39m$",
                 "^\u{1b}[37m24\u{1b}[39m$",
                 "^\u{1b}[37m90\u{1b}[39m$",
@@ -1035,7 +1497,7 @@ mod tests {
                 "^\u{1b}[37m99\u{1b}[39m$",
                 "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
+                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mCa\u{1b}[39m$",
                 "^\u{1b}[37mlc\u{1b}[39m$",
                 "^\u{1b}[37miu\u{1b}[39m$",
@@ -1065,7 +1527,6 @@ mod tests {
                 "^\u{1b}[37mg\u{1b}[39m$",
                 "^\u{1b}[37mr\u{1b}[39m$",
                 "^\u{1b}[37me\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mE\u{1b}[39m$",
                 "^\u{1b}[37mc\u{1b}[39m$",
                 "^\u{1b}[37mu\u{1b}[39m$",
@@ -1075,12 +1536,10 @@ mod tests {
                 "^\u{1b}[37mr\u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mO\u{1b}[39m$",
                 "^\u{1b}[37mM\u{1b}[39m$",
                 "^\u{1b}[37mY\u{1b}[39m$",
                 "^\u{1b}[37mA\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mA\u{1b}[39m$",
                 "^\u{1b}[37mn\u{1b}[39m$",
                 "^\u{1b}[37md\u{1b}[39m$",
@@ -1091,7 +1550,6 @@ mod tests {
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m3\u{1b}[39m$",
                 "^\u{1b}[37m8\u{1b}[39m$",
                 "^\u{1b}[37m2\u{1b}[39m$",
@@ -1107,7 +1565,6 @@ mod tests {
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mC\u{1b}[39m$",
                 "^\u{1b}[37ma\u{1b}[39m$",
                 "^\u{1b}[37ml\u{1b}[39m$",
@@ -1115,7 +1572,6 @@ mod tests {
                 "^\u{1b}[37mi\u{1b}[39m$",
                 "^\u{1b}[37mu\u{1b}[39m$",
                 "^\u{1b}[37mm\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mc\u{1b}[39m$",
                 "^\u{1b}[37ma\u{1b}[39m$",
                 "^\u{1b}[37mr\u{1b}[39m$",
@@ -1131,7 +1587,6 @@ mod tests {
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mC\u{1b}[39m$",
                 "^\u{1b}[37mo\u{1b}[39m$",
                 "^\u{1b}[37ml\u{1b}[39m$",
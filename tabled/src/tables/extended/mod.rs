@@ -50,7 +50,7 @@
 
 use std::borrow::Cow;
 
-use crate::grid::util::string::string_width;
+use crate::grid::util::string::{char_width, string_width};
 use crate::Tabled;
 
 /// `ExtendedTable` display data in a 'expanded display mode' from postgresql.
@@ -302,7 +302,7 @@ fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
             break;
         };
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        let c_width = char_width(c);
 
         // We cut the chars which takes more then 1 symbol to display,
         // in order to archive the necessary width.
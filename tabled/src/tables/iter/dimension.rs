@@ -17,6 +17,22 @@ impl IterTableDimension {
     pub fn new(width: ExactList, height: ExactList) -> Self {
         Self { width, height }
     }
+
+    pub(crate) fn set_width(&mut self, width: ExactList) {
+        self.width = width;
+    }
+
+    pub(crate) fn set_height(&mut self, height: ExactList) {
+        self.height = height;
+    }
+
+    pub(crate) fn get_widths(&self) -> &ExactList {
+        &self.width
+    }
+
+    pub(crate) fn get_heights(&self) -> &ExactList {
+        &self.height
+    }
 }
 
 impl From<IterTableDimension> for (ExactList, ExactList) {
@@ -24,14 +24,43 @@
 //! );
 //! ```
 //!
+//! # Streaming
+//!
+//! When a table is streamed (for example into a pager) a header can be re-emitted,
+//! either every N rows via [`IterTable::repeat_header`],
+//! or whenever columns get wider in a grow-as-you-go mode set via [`IterTable::grow`].
+//!
+//! ```
+//! use tabled::tables::iter::IterTable;
+//!
+//! let data = vec![vec!["id", "event"], vec!["1", "start"], vec!["2", "stop"]];
+//!
+//! let table = IterTable::new(data).repeat_header(1).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+----+-------+\n\
+//!      | id | event |\n\
+//!      +----+-------+\n\
+//!      | 1  | start |\n\
+//!      +----+-------+\n\
+//!      | id | event |\n\
+//!      +----+-------+\n\
+//!      | 2  | stop  |\n\
+//!      +----+-------+",
+//! );
+//! ```
+//!
 //! [`Table`]: crate::Table
 
-mod dimension;
+pub(crate) mod dimension;
+pub(crate) mod printer;
 pub(crate) mod utf8_writer;
 
 use std::{cmp, fmt, io};
 
 use crate::{
+    grid::util::string::string_width_multiline,
     grid::{
         colors::NoColors,
         compact::{CompactConfig, ExactDimension},
@@ -46,11 +75,11 @@ use crate::{
         },
         IntoRecords, IterRecords,
     },
-    settings::{Style, TableOption},
+    settings::{width::Truncate, Style, TableOption},
 };
 
 use self::dimension::ExactList;
-use self::{dimension::IterTableDimension, utf8_writer::UTF8Writer};
+use self::{dimension::IterTableDimension, printer::RowPrinter, utf8_writer::UTF8Writer};
 
 /// A table which consumes an [`IntoRecords`] iterator.
 ///
@@ -60,16 +89,9 @@ use self::{dimension::IterTableDimension, utf8_writer::UTF8Writer};
 #[derive(Debug, Clone)]
 pub struct IterTable<I> {
     records: I,
-    cfg: CompactConfig,
-    dim: IterTableDimension,
     table: Settings,
-}
-
-#[derive(Debug, Clone)]
-struct Settings {
-    sniff: usize,
-    count_columns: Option<usize>,
-    count_rows: Option<usize>,
+    repeat_header: Option<usize>,
+    grow: bool,
 }
 
 impl<I> IterTable<I> {
@@ -80,13 +102,9 @@ impl<I> IterTable<I> {
     {
         Self {
             records: iter,
-            cfg: create_config(),
-            dim: IterTableDimension::new(ExactList::Exact(0), ExactList::Exact(1)),
-            table: Settings {
-                sniff: 1000,
-                count_columns: None,
-                count_rows: None,
-            },
+            table: Settings::new(),
+            repeat_header: None,
+            grow: false,
         }
     }
 
@@ -97,7 +115,7 @@ impl<I> IterTable<I> {
     {
         let count_columns = self.table.count_columns.unwrap_or(0);
         let mut records = IterRecords::new(&self.records, count_columns, self.table.count_rows);
-        option.change(&mut records, &mut self.cfg, &mut self.dim);
+        option.change(&mut records, &mut self.table.cfg, &mut self.table.dim);
 
         self
     }
@@ -120,21 +138,56 @@ impl<I> IterTable<I> {
         self
     }
 
+    /// Re-emit a header (a first row) after every `count` rows.
+    ///
+    /// It's handy when a long table is streamed into a pager,
+    /// so the header doesn't scroll away.
+    pub fn repeat_header(mut self, count: usize) -> Self {
+        self.repeat_header = Some(cmp::max(count, 1));
+        self
+    }
+
+    /// Grow columns as records are read instead of cutting them to sniffed widths.
+    ///
+    /// Whenever a column gets wider a header (a first row) is printed again
+    /// with the new widths.
+    /// It has no affect if a width was set by [`IterTable::width`].
+    ///
+    /// ```
+    /// use tabled::tables::iter::IterTable;
+    ///
+    /// let data = vec![vec!["id", "path"], vec!["1", "/"], vec!["2", "/home"]];
+    ///
+    /// let table = IterTable::new(data).sniff(2).grow().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+----+------+\n\
+    ///      | id | path |\n\
+    ///      +----+------+\n\
+    ///      | 1  | /    |\n\
+    ///      +----+------+\n\
+    ///      +----+-------+\n\
+    ///      | id | path  |\n\
+    ///      +----+-------+\n\
+    ///      | 2  | /home |\n\
+    ///      +----+-------+",
+    /// );
+    /// ```
+    pub fn grow(mut self) -> Self {
+        self.grow = true;
+        self
+    }
+
     /// Set a height for each row.
     pub fn height(mut self, size: usize) -> Self {
-        let pad = self.cfg.get_padding();
-        let pad = pad.top.size + pad.bottom.size;
-        let (w, _) = self.dim.into();
-        self.dim = IterTableDimension::new(w, ExactList::Exact(size + pad));
+        self.table.set_height(size);
         self
     }
 
     /// Set a width for each column.
     pub fn width(mut self, size: usize) -> Self {
-        let pad = self.cfg.get_padding();
-        let pad = pad.left.size + pad.right.size;
-        let (_, h) = self.dim.into();
-        self.dim = IterTableDimension::new(ExactList::Exact(size + pad), h);
+        self.table.set_width(size);
         self
     }
 
@@ -167,22 +220,77 @@ impl<I> IterTable<I> {
     where
         I: IntoRecords,
     {
-        let (width, height) = self.dim.into();
-        let width = exact_list_to_exact_value(width);
-        let height = exact_list_to_exact_value(height);
-        let dims = Dims::new(width, height);
+        let dims = self.table.dims();
+
+        if self.repeat_header.is_some() || self.grow {
+            let mode = BlockMode {
+                repeat_header: self.repeat_header,
+                grow: self.grow,
+            };
+
+            return build_blocks(writer, self.records, &self.table, mode, dims);
+        }
+
+        build_grid(writer, self.records, &self.table, dims)
+    }
+}
+
+/// Settings of a table which is built from a sequence of rows.
+#[derive(Debug, Clone)]
+pub(crate) struct Settings {
+    pub(crate) cfg: CompactConfig,
+    pub(crate) dim: IterTableDimension,
+    pub(crate) sniff: usize,
+    pub(crate) count_columns: Option<usize>,
+    pub(crate) count_rows: Option<usize>,
+}
+
+impl Settings {
+    pub(crate) fn new() -> Self {
+        Self {
+            cfg: create_config(),
+            dim: IterTableDimension::new(ExactList::Exact(0), ExactList::Exact(1)),
+            sniff: 1000,
+            count_columns: None,
+            count_rows: None,
+        }
+    }
 
-        build_grid(writer, self.records, self.cfg, &self.table, dims)
+    /// Sets a height for each row, not including a padding.
+    pub(crate) fn set_height(&mut self, size: usize) {
+        let pad = self.cfg.get_padding();
+        let pad = pad.top.size + pad.bottom.size;
+        self.dim.set_height(ExactList::Exact(size + pad));
+    }
+
+    /// Sets a width for each column, not including a padding.
+    pub(crate) fn set_width(&mut self, size: usize) {
+        let pad = self.cfg.get_padding();
+        let pad = pad.left.size + pad.right.size;
+        self.dim.set_width(ExactList::Exact(size + pad));
+    }
+
+    pub(crate) fn dims(&self) -> Dims<'_> {
+        let width = exact_list_to_exact_value(self.dim.get_widths());
+        let height = exact_list_to_exact_value(self.dim.get_heights());
+        Dims::new(width, height)
     }
 }
 
+/// A way a header is re-emitted.
+#[derive(Debug, Clone, Copy)]
+struct BlockMode {
+    repeat_header: Option<usize>,
+    grow: bool,
+}
+
 fn build_grid<W: fmt::Write, I: IntoRecords>(
     writer: W,
     records: I,
-    config: CompactConfig,
     iter_cfg: &Settings,
     dims: Dims<'_>,
 ) -> Result<(), fmt::Error> {
+    let config = iter_cfg.cfg;
     let count_rows = iter_cfg.count_rows;
 
     let padding = config.get_padding();
@@ -270,6 +378,167 @@ fn build_grid<W: fmt::Write, I: IntoRecords>(
     }
 }
 
+/// Builds a table as a list of blocks each of which starts with a header.
+///
+/// A new block is started after every `repeat_header` rows,
+/// or when a row doesn't fit into current widths in a grow mode.
+///
+/// Rows are printed as soon as they are read.
+fn build_blocks<W: fmt::Write, I: IntoRecords>(
+    mut writer: W,
+    records: I,
+    iter_cfg: &Settings,
+    mode: BlockMode,
+    dims: Dims<'_>,
+) -> Result<(), fmt::Error> {
+    let config = iter_cfg.cfg;
+    let padding = config.get_padding();
+    let padding = padding.left.size + padding.right.size;
+
+    let limit = iter_cfg.count_rows.unwrap_or(usize::MAX);
+    let records = BufRows::new(LimitRows::new(records, limit), iter_cfg.sniff);
+    let records = BufColumns::from(records);
+
+    let count_columns = match iter_cfg.count_columns {
+        Some(size) => size,
+        None => records
+            .as_slice()
+            .iter()
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0),
+    };
+
+    let (mut widths, grow): (Vec<usize>, _) = match dims.width {
+        ExactValue::Exact(0) => {
+            let records = LimitColumns::new(records.as_slice(), count_columns);
+            let records = IterRecords::new(records, count_columns, None);
+            let width = ExactDimension::width(records, &config);
+            let width = width.into_iter().map(|w| cmp::max(w, padding)).collect();
+
+            (width, mode.grow)
+        }
+        width => {
+            let width = (0..count_columns).map(|col| width.get(col)).collect();
+            (width, false)
+        }
+    };
+
+    let mut rows = records
+        .iter_rows()
+        .map(|row| collect_row(row, count_columns));
+
+    let header = match rows.next() {
+        Some(header) => fit_row(header, &mut widths, padding, grow),
+        None => return Ok(()),
+    };
+
+    let mut printer = RowPrinter::new(config, dims.height);
+    let mut last_line = None;
+
+    let text = printer.print_row(header.clone(), &widths)?;
+    write_part(&mut writer, &text, &mut last_line, false)?;
+
+    let mut count_block_rows = 0;
+    for row in rows {
+        let is_full = matches!(mode.repeat_header, Some(n) if count_block_rows >= n);
+        let is_wider = grow && !is_fit(&row, &widths, padding);
+        let is_new_block = is_full || is_wider;
+        if is_new_block {
+            let text = printer.print_bottom(&widths, false)?;
+            write_part(&mut writer, &text, &mut last_line, false)?;
+            count_block_rows = 0;
+        }
+
+        let row = fit_row(row, &mut widths, padding, grow);
+
+        if is_new_block {
+            let text = printer.print_row(header.clone(), &widths)?;
+            write_part(&mut writer, &text, &mut last_line, true)?;
+        }
+
+        let text = printer.print_row(row, &widths)?;
+        write_part(&mut writer, &text, &mut last_line, false)?;
+        count_block_rows += 1;
+    }
+
+    let text = printer.print_bottom(&widths, true)?;
+    write_part(&mut writer, &text, &mut last_line, false)
+}
+
+/// Writes a rendered part of a table line by line.
+///
+/// In case of `skip_repeat` a first line of the part is skipped if it's the same as the last written one,
+/// so the same border line is not printed twice between blocks.
+fn write_part<W: fmt::Write>(
+    writer: &mut W,
+    text: &str,
+    last_line: &mut Option<String>,
+    skip_repeat: bool,
+) -> fmt::Result {
+    let mut lines = text.lines().peekable();
+    if skip_repeat && lines.peek().copied() == last_line.as_deref() {
+        let _ = lines.next();
+    }
+
+    for line in lines {
+        if last_line.is_some() {
+            writer.write_char('\n')?;
+        }
+
+        writer.write_str(line)?;
+        *last_line = Some(line.to_owned());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn collect_row<R>(row: R, count_columns: usize) -> Vec<String>
+where
+    R: IntoIterator,
+    R::Item: AsRef<str>,
+{
+    let mut row = row
+        .into_iter()
+        .take(count_columns)
+        .map(|cell| cell.as_ref().to_owned())
+        .collect::<Vec<_>>();
+    row.resize(count_columns, String::new());
+    row
+}
+
+fn is_fit(row: &[String], widths: &[usize], padding: usize) -> bool {
+    row.iter()
+        .zip(widths)
+        .all(|(text, &width)| string_width_multiline(text) + padding <= width)
+}
+
+/// Either grows widths to fit a row or cuts the row by widths.
+pub(crate) fn fit_row(
+    row: Vec<String>,
+    widths: &mut [usize],
+    padding: usize,
+    grow: bool,
+) -> Vec<String> {
+    row.into_iter()
+        .zip(widths.iter_mut())
+        .map(|(text, width)| {
+            let text_width = string_width_multiline(&text) + padding;
+            if text_width <= *width {
+                return text;
+            }
+
+            if grow {
+                *width = text_width;
+                return text;
+            }
+
+            let content_width = width.saturating_sub(padding);
+            Truncate::truncate_text(&text, content_width).into_owned()
+        })
+        .collect()
+}
+
 fn create_config() -> CompactConfig {
     CompactConfig::default()
         .set_padding(Sides::new(
@@ -294,13 +563,13 @@ fn build_records<I: IntoRecords>(
 }
 
 #[derive(Debug, Clone)]
-struct Dims<'a> {
-    width: ExactValue<'a>,
-    height: ExactValue<'a>,
+pub(crate) struct Dims<'a> {
+    pub(crate) width: ExactValue<'a>,
+    pub(crate) height: ExactValue<'a>,
 }
 
 impl<'a> Dims<'a> {
-    fn new(width: ExactValue<'a>, height: ExactValue<'a>) -> Self {
+    pub(crate) fn new(width: ExactValue<'a>, height: ExactValue<'a>) -> Self {
         Self { width, height }
     }
 }
@@ -315,9 +584,9 @@ impl Dimension for Dims<'_> {
     }
 }
 
-fn exact_list_to_exact_value(width: ExactList) -> ExactValue<'static> {
+fn exact_list_to_exact_value(width: &ExactList) -> ExactValue<'_> {
     match width {
-        ExactList::Exact(w) => ExactValue::Exact(w),
-        ExactList::List(list) => ExactValue::List(list.into()),
+        ExactList::Exact(w) => ExactValue::Exact(*w),
+        ExactList::List(list) => ExactValue::from(list.as_slice()),
     }
 }
@@ -0,0 +1,153 @@
+//! The module contains a [`RowPrinter`] which renders a table row by row.
+
+use std::fmt;
+
+use crate::{
+    grid::{
+        colors::NoColors,
+        compact::CompactConfig,
+        config::{Borders, Indent},
+        spanned::{Grid, GridConfig},
+    },
+    records::{into_records::truncate_records::ExactValue, IterRecords},
+    tables::iter::Dims,
+};
+
+/// Renders a table row by row.
+///
+/// Each row is rendered as a single row table,
+/// which borders are changed to match its position in a whole table.
+///
+/// The rendered parts are not joined by a line break.
+#[derive(Debug, Clone)]
+pub(crate) struct RowPrinter<'a> {
+    cfg: CompactConfig,
+    height: ExactValue<'a>,
+    count_rows: usize,
+    is_started: bool,
+}
+
+impl<'a> RowPrinter<'a> {
+    pub(crate) fn new(cfg: CompactConfig, height: ExactValue<'a>) -> Self {
+        Self {
+            cfg,
+            height,
+            count_rows: 0,
+            is_started: false,
+        }
+    }
+
+    pub(crate) fn print_row(
+        &mut self,
+        row: Vec<String>,
+        widths: &[usize],
+    ) -> Result<String, fmt::Error> {
+        let borders = *self.cfg.get_borders();
+
+        let mut row_borders = borders;
+        row_borders.bottom = None;
+        row_borders.bottom_left = None;
+        row_borders.bottom_right = None;
+        row_borders.bottom_intersection = None;
+
+        if self.count_rows > 0 {
+            match self.cfg.get_first_horizontal_line() {
+                Some(line) if self.count_rows == 1 => {
+                    row_borders.top = Some(line.main);
+                    row_borders.top_intersection = line.intersection;
+                    row_borders.top_left = line.connect1;
+                    row_borders.top_right = line.connect2;
+                }
+                _ => {
+                    row_borders.top = borders.horizontal;
+                    row_borders.top_intersection = borders.intersection;
+                    row_borders.top_left = borders.left_intersection;
+                    row_borders.top_right = borders.right_intersection;
+                }
+            }
+        }
+
+        let is_first = !self.is_started;
+        let rows = [row];
+        let cfg = self.create_config(row_borders, is_first, false);
+        let text = self.render(&rows, widths, cfg, self.height.clone())?;
+
+        self.count_rows += 1;
+        self.is_started = true;
+
+        Ok(text)
+    }
+
+    /// Prints a bottom line of a table and starts a new one.
+    ///
+    /// A bottom margin is printed only for the last part of a table.
+    pub(crate) fn print_bottom(
+        &mut self,
+        widths: &[usize],
+        is_last: bool,
+    ) -> Result<String, fmt::Error> {
+        if self.count_rows == 0 {
+            return Ok(String::new());
+        }
+
+        self.count_rows = 0;
+
+        // a bottom line is rendered as a top line of an empty row with no height
+        let borders = self.cfg.get_borders();
+        let bottom_borders = Borders {
+            top: borders.bottom,
+            top_left: borders.bottom_left,
+            top_right: borders.bottom_right,
+            top_intersection: borders.bottom_intersection,
+            ..Borders::default()
+        };
+
+        let rows = [vec![String::new(); widths.len()]];
+        let cfg = self.create_config(bottom_borders, false, is_last);
+        self.render(&rows, widths, cfg, ExactValue::Exact(0))
+    }
+
+    /// Creates a config for a part of a table,
+    /// so a top and a bottom margins are printed only once.
+    fn create_config(
+        &self,
+        borders: Borders<char>,
+        is_first: bool,
+        is_last: bool,
+    ) -> CompactConfig {
+        let mut margin = *self.cfg.get_margin();
+        if !is_first {
+            margin.top = Indent::zero();
+        }
+
+        if !is_last {
+            margin.bottom = Indent::zero();
+        }
+
+        CompactConfig::empty()
+            .set_borders(borders)
+            .set_borders_color(*self.cfg.get_borders_color())
+            .set_margin(margin)
+            .set_margin_color(self.cfg.get_margin_color())
+            .set_padding(*self.cfg.get_padding())
+            .set_padding_color(self.cfg.get_padding_color())
+            .set_alignment_horizontal(self.cfg.get_alignment_horizontal())
+    }
+
+    fn render(
+        &self,
+        rows: &[Vec<String>],
+        widths: &[usize],
+        cfg: CompactConfig,
+        height: ExactValue<'_>,
+    ) -> Result<String, fmt::Error> {
+        let count_columns = widths.len();
+        let records = IterRecords::new(rows, count_columns, Some(rows.len()));
+        let dims = Dims::new(ExactValue::from(widths), height);
+
+        let mut buf = String::new();
+        Grid::new(records, dims, GridConfig::from(cfg), NoColors).build(&mut buf)?;
+
+        Ok(buf)
+    }
+}
@@ -0,0 +1,160 @@
+//! This module contains a [`LiveTable`] which redraws a table in place.
+//!
+//! It keeps a previous rendering and on update it emits only
+//! ANSI cursor movements and rewrites of lines which were changed.
+//! It's handy for TUIs and status tables which are refreshed periodically.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{tables::live::LiveTable, Table};
+//!
+//! let mut live = LiveTable::new(Vec::new());
+//!
+//! live.update(&Table::new([("cpu", 10), ("mem", 20)])).unwrap();
+//! live.get_mut().clear();
+//!
+//! live.update(&Table::new([("cpu", 15), ("mem", 20)])).unwrap();
+//!
+//! // only the line with `cpu` is rewritten
+//! assert_eq!(
+//!     String::from_utf8(live.into_inner()).unwrap(),
+//!     "\u{1b}[3A\r| cpu  | 15  |\u{1b}[K\u{1b}[3B\r\u{1b}[14C",
+//! );
+//! ```
+
+use std::{
+    fmt::{self, Write},
+    io,
+};
+
+use crate::grid::util::string::string_width;
+
+/// A table printer which updates a previously printed table in place.
+///
+/// It's assumed that nothing else is written into a terminal between updates,
+/// so a cursor stays at the end of a table.
+#[derive(Debug, Clone)]
+pub struct LiveTable<W> {
+    writer: W,
+    lines: Vec<String>,
+}
+
+impl<W> LiveTable<W> {
+    /// Creates a new [`LiveTable`] structure which writes to a given writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to an underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to an underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Returns an underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Forgets a previous rendering,
+    /// so the next update prints a table from a current cursor position.
+    pub fn reset(&mut self) {
+        self.lines.clear();
+    }
+}
+
+impl<W> LiveTable<W>
+where
+    W: io::Write,
+{
+    /// Renders a table and updates changed lines of a previously printed one.
+    ///
+    /// The first call prints a whole table.
+    pub fn update<T>(&mut self, table: &T) -> io::Result<()>
+    where
+        T: fmt::Display,
+    {
+        let text = table.to_string();
+        let lines = text.split('\n').map(ToOwned::to_owned).collect::<Vec<_>>();
+
+        let mut buf = String::new();
+        if self.lines.is_empty() {
+            buf.push_str(&text);
+        } else {
+            print_diff(&mut buf, &self.lines, &lines).expect("safe");
+        }
+
+        self.lines = lines;
+
+        self.writer.write_all(buf.as_bytes())?;
+        self.writer.flush()
+    }
+}
+
+/// Prints changes between two renderings.
+///
+/// A cursor is expected to be at the end of the last previous line,
+/// and it's left at the end of the last new line.
+fn print_diff<F: Write>(f: &mut F, prev: &[String], next: &[String]) -> fmt::Result {
+    let mut cursor = prev.len() - 1;
+
+    for (i, line) in next.iter().enumerate() {
+        if i >= prev.len() {
+            move_cursor(f, cursor, i - 1)?;
+            write!(f, "\r\n{}", line)?;
+            cursor = i;
+            continue;
+        }
+
+        let is_last = i + 1 == next.len();
+        let has_stale_lines = is_last && next.len() < prev.len();
+
+        if prev[i] == *line && !has_stale_lines {
+            continue;
+        }
+
+        move_cursor(f, cursor, i)?;
+        f.write_char('\r')?;
+        f.write_str(line)?;
+        cursor = i;
+
+        if has_stale_lines {
+            // clear the rest of a screen as the table got shorter
+            f.write_str("\u{1b}[J")?;
+        } else {
+            f.write_str("\u{1b}[K")?;
+        }
+    }
+
+    // a cursor is moved to the end of the table
+    let last = next.len() - 1;
+    if cursor != last {
+        move_cursor(f, cursor, last)?;
+        f.write_char('\r')?;
+
+        let width = string_width(&next[last]);
+        if width > 0 {
+            write!(f, "\u{1b}[{}C", width)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn move_cursor<F: Write>(f: &mut F, from: usize, to: usize) -> fmt::Result {
+    if to < from {
+        write!(f, "\u{1b}[{}A", from - to)
+    } else if to > from {
+        write!(f, "\u{1b}[{}B", to - from)
+    } else {
+        Ok(())
+    }
+}
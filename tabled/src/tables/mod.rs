@@ -12,6 +12,14 @@
 //! ## [`extended`]
 //!
 //! Has a table which is useful for large amount of data.
+//!
+//! ## [`live`]
+//!
+//! A printer which updates a previously printed table in place.
+//!
+//! ## `stream`
+//!
+//! An async counterpart of [`iter`] which consumes a `Stream` of rows.
 
 pub mod compact;
 
@@ -23,4 +31,10 @@ pub mod extended;
 pub mod iter;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod live;
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub mod stream;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod table;
@@ -0,0 +1,212 @@
+//! This module contains a [`StreamTable`] table.
+//!
+//! It's an async counterpart of [`IterTable`].
+//! It consumes a [`Stream`] of rows and writes lines into an [`AsyncWrite`] as rows arrive,
+//! so a whole result set is never buffered.
+//!
+//! Widths are either set explicitly or sniffed from a number of first rows,
+//! in which case the rows are buffered until the number is reached.
+//! Cells which are wider than a column are truncated.
+//!
+//! # Example
+//!
+//! ```
+//! use futures_util::{io::Cursor, stream};
+//! use tabled::tables::stream::StreamTable;
+//!
+//! # futures_executor::block_on(async {
+//! let rows = stream::iter(vec![vec!["First", "row"], vec!["Second", "row"]]);
+//!
+//! let mut buf = Cursor::new(Vec::new());
+//! StreamTable::new(rows).build(&mut buf).await.unwrap();
+//!
+//! assert_eq!(
+//!     String::from_utf8(buf.into_inner()).unwrap(),
+//!     "+--------+-----+\n\
+//!      | First  | row |\n\
+//!      +--------+-----+\n\
+//!      | Second | row |\n\
+//!      +--------+-----+",
+//! );
+//! # });
+//! ```
+//!
+//! [`IterTable`]: crate::tables::iter::IterTable
+//! [`Stream`]: futures_util::Stream
+//! [`AsyncWrite`]: futures_util::AsyncWrite
+
+use std::{cmp, fmt, io};
+
+use futures_util::{AsyncWrite, AsyncWriteExt, Stream, StreamExt};
+
+use crate::{
+    grid::compact::{CompactConfig, ExactDimension},
+    records::{into_records::truncate_records::ExactValue, EmptyRecords, IterRecords},
+    settings::TableOption,
+    tables::iter::{
+        collect_row, dimension::IterTableDimension, fit_row, printer::RowPrinter, Settings,
+    },
+};
+
+/// A table which consumes a [`Stream`] of rows.
+///
+/// To be able to build table we need a dimensions.
+/// If no width and count_columns is set, [`StreamTable`] will sniff the records, by
+/// keeping a number of rows buffered (You can set the number via [`StreamTable::sniff`]).
+///
+/// [`Stream`]: futures_util::Stream
+#[derive(Debug, Clone)]
+pub struct StreamTable<S> {
+    stream: S,
+    table: Settings,
+}
+
+impl<S> StreamTable<S> {
+    /// Creates a new [`StreamTable`] structure.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            table: Settings::new(),
+        }
+    }
+
+    /// With is a generic function which applies options to the [`StreamTable`].
+    ///
+    /// As rows are not known beforehand, options get [`EmptyRecords`].
+    pub fn with<O>(mut self, mut option: O) -> Self
+    where
+        O: TableOption<EmptyRecords, IterTableDimension, CompactConfig>,
+    {
+        let count_columns = self.table.count_columns.unwrap_or(0);
+        let count_rows = self.table.count_rows.unwrap_or(0);
+        let mut records = EmptyRecords::new(count_rows, count_columns);
+        option.change(&mut records, &mut self.table.cfg, &mut self.table.dim);
+
+        self
+    }
+
+    /// Limit a number of columns.
+    pub fn columns(mut self, count_columns: usize) -> Self {
+        self.table.count_columns = Some(count_columns);
+        self
+    }
+
+    /// Limit a number of rows.
+    pub fn rows(mut self, count_rows: usize) -> Self {
+        self.table.count_rows = Some(count_rows);
+        self
+    }
+
+    /// Limit an amount of rows will be read for dimension estimations.
+    pub fn sniff(mut self, count: usize) -> Self {
+        self.table.sniff = count;
+        self
+    }
+
+    /// Set a height for each row.
+    pub fn height(mut self, size: usize) -> Self {
+        self.table.set_height(size);
+        self
+    }
+
+    /// Set a width for each column.
+    pub fn width(mut self, size: usize) -> Self {
+        self.table.set_width(size);
+        self
+    }
+
+    /// Format table into [`AsyncWrite`]r.
+    ///
+    /// Each row is written as soon as it's read from a stream
+    /// (except rows buffered for sniffing).
+    ///
+    /// [`AsyncWrite`]: futures_util::AsyncWrite
+    pub async fn build<W>(self, mut writer: W) -> io::Result<()>
+    where
+        S: Stream + Unpin,
+        S::Item: IntoIterator,
+        <S::Item as IntoIterator>::Item: AsRef<str>,
+        W: AsyncWrite + Unpin,
+    {
+        let padding = self.table.cfg.get_padding();
+        let padding = padding.left.size + padding.right.size;
+
+        let limit = self.table.count_rows.unwrap_or(usize::MAX);
+        let mut stream = self.stream.take(limit);
+
+        let dims = self.table.dims();
+
+        let dont_sniff =
+            !matches!(dims.width, ExactValue::Exact(0)) && self.table.count_columns.is_some();
+
+        let mut buf: Vec<Vec<String>> = Vec::new();
+        if !dont_sniff {
+            while buf.len() < self.table.sniff {
+                match stream.next().await {
+                    Some(row) => buf.push(row.into_iter().map(|s| s.as_ref().to_owned()).collect()),
+                    None => break,
+                }
+            }
+        }
+
+        let count_columns = match self.table.count_columns {
+            Some(size) => size,
+            None => buf.iter().map(|row| row.len()).max().unwrap_or(0),
+        };
+
+        for row in &mut buf {
+            row.resize(count_columns, String::new());
+        }
+
+        let mut widths = match dims.width {
+            ExactValue::Exact(0) => {
+                let records = IterRecords::new(&buf, count_columns, None);
+                let width = ExactDimension::width(records, &self.table.cfg);
+                width.into_iter().map(|w| cmp::max(w, padding)).collect()
+            }
+            width => (0..count_columns)
+                .map(|col| width.get(col))
+                .collect::<Vec<_>>(),
+        };
+
+        let mut printer = RowPrinter::new(self.table.cfg, dims.height);
+
+        let mut is_first = true;
+        for row in buf {
+            let row = fit_row(row, &mut widths, padding, false);
+            let text = printer.print_row(row, &widths).map_err(fmt_error)?;
+            writer
+                .write_all(join_line(text, is_first).as_bytes())
+                .await?;
+            is_first = false;
+        }
+
+        while let Some(row) = stream.next().await {
+            let row = collect_row(row, count_columns);
+            let row = fit_row(row, &mut widths, padding, false);
+            let text = printer.print_row(row, &widths).map_err(fmt_error)?;
+            writer
+                .write_all(join_line(text, is_first).as_bytes())
+                .await?;
+            is_first = false;
+        }
+
+        let text = printer.print_bottom(&widths, true).map_err(fmt_error)?;
+        writer.write_all(join_line(text, false).as_bytes()).await?;
+
+        writer.flush().await
+    }
+}
+
+/// Puts a line break before a part of a table unless it's a first or an empty one.
+fn join_line(text: String, is_first: bool) -> String {
+    if is_first || text.is_empty() {
+        return text;
+    }
+
+    format!("\n{}", text)
+}
+
+fn fmt_error(err: fmt::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, err)
+}
@@ -0,0 +1,100 @@
+//! The module contains helpers to convert a [`Table`] into other markup formats.
+
+use crate::{
+    grid::config::{AlignmentHorizontal, Entity},
+    records::{ExactRecords, Records},
+};
+
+use super::Table;
+
+/// Returns a text of each cell of a [`Table`],
+/// where cells covered by a span are empty.
+///
+/// ```
+/// use tabled::{settings::{Modify, Span}, tables::table::visible_cells, Table};
+///
+/// let table = Table::new([["a", "b"]])
+///     .with(Modify::new((1, 0)).with(Span::column(2)))
+///     .to_owned();
+///
+/// assert_eq!(
+///     visible_cells(&table),
+///     [["0", "1"], ["a", ""]],
+/// );
+/// ```
+pub fn visible_cells(table: &Table) -> Vec<Vec<String>> {
+    let records = table.get_records();
+    let cfg = table.get_config();
+    let (count_rows, count_columns) = (records.count_rows(), records.count_columns());
+
+    (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|col| {
+                    if cfg.is_cell_visible((row, col)) {
+                        records.get_cell((row, col)).clone()
+                    } else {
+                        String::new()
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns a horizontal alignment of each column of a [`Table`].
+///
+/// It's the most used alignment in a column omitting a header,
+/// as a header is usually styled separately.
+///
+/// A left alignment is a default one, which can't be told apart from an explicitly set one,
+/// so it's returned as [`None`].
+///
+/// ```
+/// use tabled::{
+///     grid::config::AlignmentHorizontal,
+///     settings::{object::Columns, Alignment, Modify},
+///     tables::table::column_alignments,
+///     Table,
+/// };
+///
+/// let table = Table::new([[1, 2], [3, 4]])
+///     .with(Modify::new(Columns::last()).with(Alignment::right()))
+///     .to_owned();
+///
+/// assert_eq!(
+///     column_alignments(&table),
+///     [None, Some(AlignmentHorizontal::Right)],
+/// );
+/// ```
+pub fn column_alignments(table: &Table) -> Vec<Option<AlignmentHorizontal>> {
+    let records = table.get_records();
+    let cfg = table.get_config();
+    let (count_rows, count_columns) = (records.count_rows(), records.count_columns());
+
+    let body = if count_rows > 1 { 1 } else { 0 };
+
+    (0..count_columns)
+        .map(|col| {
+            // a left alignment goes last so it's chosen in case of equal usage.
+            let mut usage = [
+                (AlignmentHorizontal::Right, 0),
+                (AlignmentHorizontal::Center, 0),
+                (AlignmentHorizontal::Left, 0),
+            ];
+
+            for row in body..count_rows {
+                let alignment = *cfg.get_alignment_horizontal(Entity::Cell(row, col));
+                if let Some((_, count)) = usage.iter_mut().find(|(a, _)| *a == alignment) {
+                    *count += 1;
+                }
+            }
+
+            usage
+                .iter()
+                .max_by_key(|(_, count)| *count)
+                .map(|(alignment, _)| *alignment)
+                .filter(|alignment| *alignment != AlignmentHorizontal::Left)
+        })
+        .collect()
+}
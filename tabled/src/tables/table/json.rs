@@ -0,0 +1,184 @@
+//! The module contains a JSON representation of a [`Table`].
+
+use std::collections::HashSet;
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, AlignmentVertical, Entity},
+        dimension::{Dimension, Estimate},
+    },
+    records::{ExactRecords, Records},
+};
+
+use super::Table;
+
+impl Table {
+    /// Returns a JSON representation of the table records.
+    ///
+    /// It's an array of objects keyed by a header (a first row),
+    /// so it's an inverse of `json_to_table` for a flat data.
+    ///
+    /// If a header has empty or repeated names it's not used as keys,
+    /// then it's an array of arrays, which includes the first row.
+    ///
+    /// ```
+    /// use tabled::Table;
+    /// use serde_json::json;
+    ///
+    /// let table = Table::new([("Debian", 11), ("Arch", 2023)]);
+    ///
+    /// assert_eq!(
+    ///     table.to_json(),
+    ///     json!([
+    ///         { "&str": "Debian", "i32": "11" },
+    ///         { "&str": "Arch", "i32": "2023" },
+    ///     ]),
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn to_json(&self) -> Value {
+        let records = &self.records;
+        let (count_rows, count_columns) = (records.count_rows(), records.count_columns());
+
+        let mut rows = (0..count_rows).map(|row| {
+            (0..count_columns)
+                .map(|col| records.get_cell((row, col)).clone())
+                .collect::<Vec<_>>()
+        });
+
+        let header = match rows.next() {
+            Some(header) => header,
+            None => return Value::Array(Vec::new()),
+        };
+
+        let mut names = HashSet::new();
+        let is_header = header
+            .iter()
+            .all(|name| !name.is_empty() && names.insert(name));
+        if !is_header {
+            let rows = std::iter::once(header).chain(rows);
+            return Value::Array(rows.map(|row| json!(row)).collect());
+        }
+
+        let objects = rows
+            .map(|row| {
+                let object = header
+                    .iter()
+                    .cloned()
+                    .zip(row.into_iter().map(Value::String))
+                    .collect::<Map<_, _>>();
+
+                Value::Object(object)
+            })
+            .collect();
+
+        Value::Array(objects)
+    }
+
+    /// Returns a JSON representation of the table layout.
+    ///
+    /// It contains a header (a first row), rows, spans, alignment of each cell
+    /// and widths and heights which the table would be printed with,
+    /// so the table could be re-rendered elsewhere.
+    ///
+    /// ```
+    /// use tabled::Table;
+    /// use serde_json::json;
+    ///
+    /// let table = Table::new([["Hello", "World"]]);
+    ///
+    /// assert_eq!(
+    ///     table.to_json_layout(),
+    ///     json!({
+    ///         "header": ["0", "1"],
+    ///         "rows": [["Hello", "World"]],
+    ///         "spans": [],
+    ///         "alignment": {
+    ///             "horizontal": [["left", "left"], ["left", "left"]],
+    ///             "vertical": [["top", "top"], ["top", "top"]],
+    ///         },
+    ///         "widths": [7, 7],
+    ///         "heights": [1, 1],
+    ///     }),
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn to_json_layout(&self) -> Value {
+        let records = &self.records;
+        let cfg = &self.config;
+        let (count_rows, count_columns) = (records.count_rows(), records.count_columns());
+
+        let mut data = (0..count_rows).map(|row| {
+            let cells = (0..count_columns)
+                .map(|col| Value::String(records.get_cell((row, col)).clone()))
+                .collect();
+            Value::Array(cells)
+        });
+
+        let header = data.next().unwrap_or_else(|| Value::Array(Vec::new()));
+        let rows = data.collect::<Vec<_>>();
+
+        let mut spans = Vec::new();
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let rows = cfg.get_row_span((row, col)).unwrap_or(1);
+                let columns = cfg.get_column_span((row, col)).unwrap_or(1);
+                if rows > 1 || columns > 1 {
+                    spans.push(json!({
+                        "row": row,
+                        "column": col,
+                        "rows": rows,
+                        "columns": columns,
+                    }));
+                }
+            }
+        }
+
+        let alignment_matrix = |f: &dyn Fn(Entity) -> &'static str| {
+            (0..count_rows)
+                .map(|row| {
+                    (0..count_columns)
+                        .map(|col| Value::from(f(Entity::Cell(row, col))))
+                        .collect()
+                })
+                .collect::<Vec<Value>>()
+        };
+
+        let horizontal = alignment_matrix(&|entity| match cfg.get_alignment_horizontal(entity) {
+            AlignmentHorizontal::Left => "left",
+            AlignmentHorizontal::Center => "center",
+            AlignmentHorizontal::Right => "right",
+        });
+        let vertical = alignment_matrix(&|entity| match cfg.get_alignment_vertical(entity) {
+            AlignmentVertical::Top => "top",
+            AlignmentVertical::Center => "center",
+            AlignmentVertical::Bottom => "bottom",
+        });
+
+        let mut dimension = self.dimension.clone();
+        if count_rows > 0 && count_columns > 0 {
+            dimension.estimate(records, cfg);
+        }
+
+        let widths = (0..count_columns)
+            .map(|col| dimension.get_width(col))
+            .collect::<Vec<_>>();
+        let heights = (0..count_rows)
+            .map(|row| dimension.get_height(row))
+            .collect::<Vec<_>>();
+
+        json!({
+            "header": header,
+            "rows": rows,
+            "spans": spans,
+            "alignment": {
+                "horizontal": horizontal,
+                "vertical": vertical,
+            },
+            "widths": widths,
+            "heights": heights,
+        })
+    }
+}
@@ -1,6 +1,9 @@
 //! This module contains a main table representation [`Table`].
 
 mod dimension;
+mod export;
+#[cfg(feature = "serde_json")]
+mod json;
 
 use core::ops::{Deref, DerefMut};
 use std::{borrow::Cow, collections::HashMap, fmt, iter::FromIterator};
@@ -23,6 +26,7 @@ use crate::{
 };
 
 pub use dimension::TableDimension;
+pub use export::{column_alignments, visible_cells};
 use papergrid::colors::NoColors;
 
 /// The structure provides an interface for building a table for types that implements [`Tabled`].
@@ -241,6 +245,18 @@ impl Table {
     pub fn get_config(&self) -> &ColoredConfig {
         &self.config
     }
+
+    /// Returns a table records.
+    pub fn get_records(&self) -> &VecRecords<String> {
+        &self.records
+    }
+
+    /// Returns a table dimension.
+    ///
+    /// It's not estimated yet, unless widths or heights were set by a setting.
+    pub fn get_dimension(&self) -> &TableDimension<'static> {
+        &self.dimension
+    }
 }
 
 impl fmt::Display for Table {
@@ -402,6 +418,11 @@ impl ColoredConfig {
         let _ = self.colors.insert(pos, color);
         self
     }
+
+    /// Returns a list of colors set for cells.
+    pub fn get_colors(&self) -> &HashMap<Position, AnsiColor<'static>> {
+        &self.colors
+    }
 }
 
 impl Deref for ColoredConfig {
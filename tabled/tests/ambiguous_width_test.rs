@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    grid::util::string::{string_width, string_width_ambiguous},
+    settings::{formatting::AmbiguousWidth, object::Rows, Modify, Style, Width},
+    Table,
+};
+
+use crate::util::static_table;
+
+mod util;
+
+#[test]
+fn ambiguous_width_estimation_test() {
+    assert_eq!(string_width_ambiguous("±1", AmbiguousWidth::Wide), 3);
+    assert_eq!(string_width_ambiguous("§", AmbiguousWidth::Wide), 2);
+
+    let table = Table::new(["±1", "§2"])
+        .with(Style::markdown())
+        .with(AmbiguousWidth::Wide)
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| ±1  |"
+            "| §2  |"
+        )
+    );
+}
+
+#[test]
+fn ambiguous_width_borders_test() {
+    let table = Table::new(["abcd", "abc"])
+        .with(Style::modern())
+        .with(AmbiguousWidth::Wide)
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┐"
+            "│ &str │"
+            "├───┤"
+            "│ abcd │"
+            "├───┤"
+            "│ abc  │"
+            "└───┘"
+        )
+    );
+}
+
+#[test]
+fn ambiguous_width_truncate_test() {
+    let table = Table::new(["±±±"])
+        .with(Style::markdown())
+        .with(AmbiguousWidth::Wide)
+        .with(Modify::new(Rows::new(1..)).with(Width::truncate(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| ±   |"
+        )
+    );
+}
+
+#[test]
+fn ambiguous_width_narrow_test() {
+    assert_eq!(string_width("±1"), 2);
+    assert_eq!(string_width_ambiguous("±1", AmbiguousWidth::Narrow), 2);
+
+    let table = Table::new(["±1", "§2"]).with(Style::markdown()).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| ±1   |"
+            "| §2   |"
+        )
+    );
+
+    let table = Table::new(["±1", "§2"])
+        .with(Style::markdown())
+        .with(AmbiguousWidth::Wide)
+        .with(AmbiguousWidth::Narrow)
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| ±1   |"
+            "| §2   |"
+        )
+    );
+}
+
+#[test]
+fn ambiguous_width_is_per_table_test() {
+    let wide = Table::new(["§"])
+        .with(Style::modern())
+        .with(AmbiguousWidth::Wide)
+        .to_string();
+    let narrow = Table::new(["§"]).with(Style::modern()).to_string();
+
+    assert_eq!(
+        wide,
+        static_table!(
+            "┌───┐"
+            "│ &str │"
+            "├───┤"
+            "│ §   │"
+            "└───┘"
+        )
+    );
+    assert_eq!(
+        narrow,
+        static_table!(
+            "┌──────┐"
+            "│ &str │"
+            "├──────┤"
+            "│ §    │"
+            "└──────┘"
+        )
+    );
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{Chart, Padding, Settings, Style, Width},
+    Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    chart_bars_test,
+    Table::new([("sda", "capacity"), ("sdb", "80"), ("sdc", "10"), ("sdd", "x")])
+        .with(Style::psql())
+        .with(Chart::bars(1)),
+    " &str | &str     "
+    "------+----------"
+    " sda  | capacity "
+    " sdb  | ████████ "
+    " sdc  | █        "
+    " sdd  | x        "
+);
+
+test_table!(
+    chart_bars_partial_test,
+    Table::new([("a", "used"), ("b", "3"), ("c", "2"), ("d", "-1")])
+        .with(Style::psql())
+        .with(Chart::bars(1)),
+    " &str | &str "
+    "------+------"
+    " a    | used "
+    " b    | ████ "
+    " c    | ██▋  "
+    " d    |      "
+);
+
+test_table!(
+    chart_bars_with_width_test,
+    Table::new([("sda", "100"), ("sdb", "25")])
+        .with(Style::psql())
+        .with(Settings::new(Width::increase(20), Chart::bars(1))),
+    " &str     | &str    "
+    "----------+---------"
+    " sda      | ███████ "
+    " sdb      | █▊      "
+);
+
+test_table!(
+    chart_bars_after_truncate_test,
+    Table::new([("sda", "capacity"), ("sdb", "100"), ("sdc", "50")])
+        .with(Style::psql())
+        .with(Width::truncate(12))
+        .with(Chart::bars(1)),
+    " & | &str   "
+    "---+--------"
+    " s | capaci "
+    " s | ██████ "
+    " s | ███    "
+);
+
+test_table!(
+    chart_bars_padding_test,
+    Table::new([("sda", "10"), ("sdb", "5")])
+        .with(Style::psql())
+        .with(Padding::zero())
+        .with(Chart::bars(1)),
+    "&str|&str"
+    "----+----"
+    "sda |████"
+    "sdb |██  "
+);
+
+test_table!(
+    chart_sparkline_test,
+    Table::new([("cpu", "1, 2, 3, 4, 5, 6, 7, 8"), ("mem", "5 5 5"), ("io", "-")])
+        .with(Style::psql())
+        .with(Chart::sparkline(1)),
+    " &str | &str     "
+    "------+----------"
+    " cpu  | ▁▂▃▄▅▆▇█ "
+    " mem  | ▁▁▁      "
+    " io   | -        "
+);
+
+test_table!(
+    chart_sparkline_truncated_test,
+    Builder::from_iter([["x", "1 2 3 4 5 6 7 8 9"]])
+        .build()
+        .with(Style::psql())
+        .with(Padding::zero())
+        .with(Settings::new(Width::list([1, 3]), Chart::sparkline(1))),
+    "x|▁▅█"
+    "-+---"
+);
+
+test_table!(
+    chart_out_of_bounds_test,
+    Table::new([("a", "1")]).with(Style::psql()).with(Chart::bars(5)).with(Chart::sparkline(5)),
+    " &str | &str "
+    "------+------"
+    " a    | 1    "
+);
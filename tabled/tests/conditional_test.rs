@@ -0,0 +1,92 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{
+    object::{Columns, Rows, Segment},
+    Color, Conditional, Modify, Style,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    conditional_text_test,
+    create_table::<3, 3>()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Conditional::when(|s| s.starts_with("1-"), Color::FG_RED))),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |   0-1    |   0-2    "
+    " 1 |   \u{1b}[31m1-0\u{1b}[39m    |   \u{1b}[31m1-1\u{1b}[39m    |   \u{1b}[31m1-2\u{1b}[39m    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    conditional_number_test,
+    tabled::Table::new([["-1.5", "x"], ["2", " -3 "]])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Conditional::number(|n| n < 0.0, Color::FG_RED))),
+    " 0    | 1    "
+    "------+------"
+    " \u{1b}[31m-1.5\u{1b}[39m | x    "
+    " 2    | \u{1b}[31m -3 \u{1b}[39m "
+);
+
+test_table!(
+    conditional_max_min_test,
+    tabled::Table::new([(1, 10), (5, 10), (3, 2)])
+        .with(Style::psql())
+        .with(Modify::new(Columns::new(..)).with(Conditional::max(Color::BOLD)))
+        .with(Modify::new(Columns::new(..)).with(Conditional::min(Color::FG_BLUE))),
+    " i32 | i32 "
+    "-----+-----"
+    " \u{1b}[34m1\u{1b}[39m   | \u{1b}[1m10\u{1b}[22m  "
+    " \u{1b}[1m5\u{1b}[22m   | \u{1b}[1m10\u{1b}[22m  "
+    " 3   | \u{1b}[34m2\u{1b}[39m   "
+);
+
+test_table!(
+    conditional_max_range_of_target_test,
+    tabled::Table::new([(1, 10), (5, 10), (3, 2)])
+        .with(Style::psql())
+        .with(Modify::new(Rows::last()).with(Conditional::max(Color::BOLD))),
+    " i32 | i32 "
+    "-----+-----"
+    " 1   | 10  "
+    " 5   | 10  "
+    " \u{1b}[1m3\u{1b}[22m   | \u{1b}[1m2\u{1b}[22m   "
+);
+
+test_table!(
+    conditional_gradient_test,
+    tabled::Table::new([0, 1, 4])
+        .with(Style::psql())
+        .with(Modify::new(Columns::new(..)).with(Conditional::gradient((0, 0, 255), (255, 0, 0)).background())),
+    " i32 "
+    "-----"
+    " \u{1b}[48;2;0;0;255m0\u{1b}[49m   "
+    " \u{1b}[48;2;64;0;191m1\u{1b}[49m   "
+    " \u{1b}[48;2;255;0;0m4\u{1b}[49m   "
+);
+
+test_table!(
+    conditional_gradient_single_value_test,
+    tabled::Table::new([7])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Conditional::gradient((0, 0, 0), (255, 255, 255)))),
+    " i32 "
+    "-----"
+    " \u{1b}[38;2;0;0;0m7\u{1b}[39m   "
+);
+
+test_table!(
+    conditional_trend_test,
+    tabled::Table::new([(1, 5), (2, 5), (0, 6)])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Conditional::trend().symbols('+', '-', '='))),
+    " i32 | i32 "
+    "-----+-----"
+    " 1   | 5   "
+    " 2 + | 5 = "
+    " 0 - | 6 + "
+);
@@ -135,6 +135,7 @@ mod tuple {
     test_tuple!(order_3, t: { u8 #[tabled(order = 0)] u8 u8}, init: { 0 1 2 }, expected: ["1", "0", "2"], ["1", "0", "2"],);
     test_tuple!(order_4, t: { u8 #[tabled(order = 1)] u8 u8}, init: { 0 1 2 }, expected: ["0", "1", "2"], ["0", "1", "2"],);
     test_tuple!(order_5, t: { u8 #[tabled(order = 2)] u8 u8}, init: { 0 1 2 }, expected: ["0", "2", "1"], ["0", "2", "1"],);
+    test_tuple!(link, t: { #[tabled(link = "1")] u8 sstr }, init: { 0 "https://a.b" }, expected: ["0", "1"], ["\u{1b}]8;;https://a.b\u{1b}\\0\u{1b}]8;;\u{1b}\\", "https://a.b"],);
     test_tuple!(order_6, t: { u8 u8 #[tabled(order = 0)] u8}, init: { 0 1 2 }, expected: ["2", "0", "1"], ["2", "0", "1"],);
     test_tuple!(order_7, t: { u8 u8 #[tabled(order = 1)] u8}, init: { 0 1 2 }, expected: ["0", "2", "1"], ["0", "2", "1"],);
     test_tuple!(order_8, t: { u8 u8 #[tabled(order = 2)] u8}, init: { 0 1 2 }, expected: ["0", "1", "2"], ["0", "1", "2"],);
@@ -295,6 +296,18 @@ mod enum_ {
             Bikecycle("A bike", Bike { brand: "Canyon", price: 2000.0 })=> ["", "", "A bike", "Canyon", "2000", ""],
     );
 
+    test_enum!(
+        inline_variant_with_link,
+        t: {
+            #[tabled(inline)] Crate { #[tabled(link = "url")] name: sstr, #[tabled(skip)] url: sstr }
+            #[tabled(inline)] Page( #[tabled(link = "0")] sstr )
+        },
+        headers: ["name", "0"],
+        tests:
+            Crate { name: "tabled", url: "https://a.b" } => ["\u{1b}]8;;https://a.b\u{1b}\\tabled\u{1b}]8;;\u{1b}\\", ""],
+            Page("https://c.d") => ["", "\u{1b}]8;;https://c.d\u{1b}\\https://c.d\u{1b}]8;;\u{1b}\\"],
+    );
+
     test_enum!(
         inline_field_with_display_function,
         t: {
@@ -751,6 +764,40 @@ mod structure {
         init: { f1: 0, f2: Some("v2") }
         expected: ["f1", "f2"], ["0", "1 2 3"]
     );
+    test_struct!(
+        link,
+        t: {
+            #[tabled(link = "url")]
+            name: sstr,
+            #[tabled(skip)]
+            url: sstr,
+        }
+        init: { name: "tabled", url: "https://docs.rs/tabled" }
+        expected: ["name"], ["\u{1b}]8;;https://docs.rs/tabled\u{1b}\\tabled\u{1b}]8;;\u{1b}\\"]
+    );
+    test_struct!(
+        link_multiline,
+        t: {
+            #[tabled(link = "url")]
+            name: sstr,
+            url: sstr,
+        }
+        init: { name: "a\nb", url: "https://a.b" }
+        expected: ["name", "url"], [
+            "\u{1b}]8;;https://a.b\u{1b}\\a\u{1b}]8;;\u{1b}\\\n\u{1b}]8;;https://a.b\u{1b}\\b\u{1b}]8;;\u{1b}\\",
+            "https://a.b"
+        ]
+    );
+    test_struct!(
+        link_empty_url,
+        t: {
+            #[tabled(link = "url")]
+            name: sstr,
+            url: sstr,
+        }
+        init: { name: "tabled", url: "" }
+        expected: ["name", "url"], ["tabled", ""]
+    );
     test_struct!(
         display_with_self_static_method,
         t: {
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{object::Columns, Hyperlink, Modify, Style, Width},
+    Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    hyperlink_test,
+    Table::new([("tabled", 1), ("papergrid", 2)])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Hyperlink::url("https://a.b"))),
+    " \u{1b}]8;;https://a.b\u{1b}\\&str\u{1b}]8;;\u{1b}\\      | i32 "
+    "-----------+-----"
+    " \u{1b}]8;;https://a.b\u{1b}\\tabled\u{1b}]8;;\u{1b}\\    | 1   "
+    " \u{1b}]8;;https://a.b\u{1b}\\papergrid\u{1b}]8;;\u{1b}\\ | 2   "
+);
+
+test_table!(
+    hyperlink_content_test,
+    Table::new(["tabled", ""])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Hyperlink::content(|s| {
+            if s.is_empty() || s == "&str" {
+                String::new()
+            } else {
+                format!("https://crates.io/crates/{}", s)
+            }
+        }))),
+    " &str   "
+    "--------"
+    " \u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\tabled\u{1b}]8;;\u{1b}\\ "
+    "        "
+);
+
+test_table!(
+    hyperlink_wrap_test,
+    Table::new(["hello world"])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Hyperlink::url("https://a.b")))
+        .with(Modify::new(Columns::first()).with(Width::wrap(5).keep_words())),
+    " \u{1b}]8;;https://a.b\u{1b}\\&str\u{1b}]8;;\u{1b}\\  "
+    "-------"
+    " \u{1b}]8;;https://a.b\u{1b}\\hello\u{1b}]8;;\u{1b}\\ "
+    " \u{1b}]8;;https://a.b\u{1b}\\world\u{1b}]8;;\u{1b}\\ "
+);
+
+test_table!(
+    hyperlink_truncate_test,
+    Table::new(["hello world"])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Hyperlink::url("https://a.b")))
+        .with(Modify::new(Columns::first()).with(Width::truncate(5).suffix("."))),
+    " \u{1b}]8;;https://a.b\u{1b}\\&str\u{1b}]8;;\u{1b}\\  "
+    "-------"
+    " \u{1b}]8;;https://a.b\u{1b}\\hell.\u{1b}]8;;\u{1b}\\ "
+);
+
+test_table!(
+    hyperlink_total_width_test,
+    Table::new(["hello world"])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Hyperlink::url("https://a.b")))
+        .with(Width::wrap(7)),
+    " \u{1b}]8;;https://a.b\u{1b}\\&str\u{1b}]8;;\u{1b}\\  "
+    "-------"
+    " \u{1b}]8;;https://a.b\u{1b}\\hello\u{1b}]8;;\u{1b}\\ "
+    " \u{1b}]8;;https://a.b\u{1b}\\ worl\u{1b}]8;;\u{1b}\\ "
+    " \u{1b}]8;;https://a.b\u{1b}\\d\u{1b}]8;;\u{1b}\\     "
+);
+
+test_table!(
+    hyperlink_wrap_trailing_space_test,
+    Table::new(["hello world "])
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Hyperlink::url("https://a.b")))
+        .with(Modify::new(Columns::first()).with(Width::wrap(5).keep_words())),
+    " \u{1b}]8;;https://a.b\u{1b}\\&str\u{1b}]8;;\u{1b}\\  "
+    "-------"
+    " \u{1b}]8;;https://a.b\u{1b}\\hello\u{1b}]8;;\u{1b}\\ "
+    " \u{1b}]8;;https://a.b\u{1b}\\world\u{1b}]8;;\u{1b}\\ "
+);
@@ -2,7 +2,7 @@
 
 mod util;
 
-use tabled::tables::iter::IterTable;
+use tabled::{settings::Margin, tables::iter::IterTable};
 use util::{create_matrix, test_table};
 
 test_table!(
@@ -161,3 +161,232 @@ test_table!(
     "| 0   | 0   | 0   | 0   |"
     "+-----+-----+-----+-----+"
 );
+
+test_table!(
+    iter_table_repeat_header,
+    IterTable::new(create_matrix::<5, 2>()).repeat_header(2).to_string(),
+    "+-----+-----+"
+    "| 0-0 | 0-1 |"
+    "+-----+-----+"
+    "| 1-0 | 1-1 |"
+    "+-----+-----+"
+    "| 2-0 | 2-1 |"
+    "+-----+-----+"
+    "| 0-0 | 0-1 |"
+    "+-----+-----+"
+    "| 3-0 | 3-1 |"
+    "+-----+-----+"
+    "| 4-0 | 4-1 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    iter_table_repeat_header_margin,
+    IterTable::new(create_matrix::<3, 2>())
+        .with(Margin::new(1, 1, 1, 1).fill('>', '<', 'V', '^'))
+        .repeat_header(1)
+        .to_string(),
+    "VVVVVVVVVVVVVVV"
+    ">+-----+-----+<"
+    ">| 0-0 | 0-1 |<"
+    ">+-----+-----+<"
+    ">| 1-0 | 1-1 |<"
+    ">+-----+-----+<"
+    ">| 0-0 | 0-1 |<"
+    ">+-----+-----+<"
+    ">| 2-0 | 2-1 |<"
+    ">+-----+-----+<"
+    "^^^^^^^^^^^^^^^"
+);
+
+test_table!(
+    iter_table_repeat_header_rows,
+    IterTable::new(create_matrix::<5, 2>()).repeat_header(1).rows(3).to_string(),
+    "+-----+-----+"
+    "| 0-0 | 0-1 |"
+    "+-----+-----+"
+    "| 1-0 | 1-1 |"
+    "+-----+-----+"
+    "| 0-0 | 0-1 |"
+    "+-----+-----+"
+    "| 2-0 | 2-1 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    iter_table_repeat_header_sniff_cut,
+    IterTable::new(
+        vec![
+            vec!["12", "12"],
+            vec!["0", "0"],
+            vec!["023", "123"],
+        ]
+    )
+    .sniff(2)
+    .repeat_header(1)
+    .to_string(),
+    "+----+----+"
+    "| 12 | 12 |"
+    "+----+----+"
+    "| 0  | 0  |"
+    "+----+----+"
+    "| 12 | 12 |"
+    "+----+----+"
+    "| 02 | 12 |"
+    "+----+----+"
+);
+
+test_table!(
+    iter_table_repeat_header_only_header,
+    IterTable::new(vec![vec!["id", "name"]]).repeat_header(1).to_string(),
+    "+----+------+"
+    "| id | name |"
+    "+----+------+"
+);
+
+test_table!(
+    iter_table_repeat_header_empty,
+    IterTable::new(Vec::<Vec<String>>::new())
+        .repeat_header(1)
+        .to_string(),
+    ""
+);
+
+test_table!(
+    iter_table_grow,
+    IterTable::new(
+        vec![
+            vec!["id", "path"],
+            vec!["1", "/"],
+            vec!["2", "/usr"],
+            vec!["3", "/home"],
+            vec!["4", "/var"],
+            vec!["1000", "/"],
+        ]
+    )
+    .sniff(1)
+    .grow()
+    .to_string(),
+    "+----+------+"
+    "| id | path |"
+    "+----+------+"
+    "| 1  | /    |"
+    "+----+------+"
+    "| 2  | /usr |"
+    "+----+------+"
+    "+----+-------+"
+    "| id | path  |"
+    "+----+-------+"
+    "| 3  | /home |"
+    "+----+-------+"
+    "| 4  | /var  |"
+    "+----+-------+"
+    "+------+-------+"
+    "| id   | path  |"
+    "+------+-------+"
+    "| 1000 | /     |"
+    "+------+-------+"
+);
+
+test_table!(
+    iter_table_grow_with_repeat_header,
+    IterTable::new(
+        vec![
+            vec!["id", "path"],
+            vec!["1", "/"],
+            vec!["2", "/home"],
+            vec!["3", "/"],
+            vec!["4", "/"],
+        ]
+    )
+    .sniff(1)
+    .grow()
+    .repeat_header(2)
+    .to_string(),
+    "+----+------+"
+    "| id | path |"
+    "+----+------+"
+    "| 1  | /    |"
+    "+----+------+"
+    "+----+-------+"
+    "| id | path  |"
+    "+----+-------+"
+    "| 2  | /home |"
+    "+----+-------+"
+    "| 3  | /     |"
+    "+----+-------+"
+    "| id | path  |"
+    "+----+-------+"
+    "| 4  | /     |"
+    "+----+-------+"
+);
+
+test_table!(
+    iter_table_grow_is_ignored_with_width,
+    IterTable::new(vec![vec!["id", "path"], vec!["1", "/home"]])
+        .width(4)
+        .grow()
+        .to_string(),
+    "+------+------+"
+    "| id   | path |"
+    "+------+------+"
+    "| 1    | /hom |"
+    "+------+------+"
+);
+
+#[test]
+fn iter_table_repeat_header_build() {
+    let mut buf = Vec::new();
+    IterTable::new(create_matrix::<3, 1>())
+        .repeat_header(1)
+        .build(&mut buf)
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "+-----+\n| 0-0 |\n+-----+\n| 1-0 |\n+-----+\n| 0-0 |\n+-----+\n| 2-0 |\n+-----+"
+    );
+}
+
+#[test]
+fn iter_table_grow_prints_rows_before_iterator_ends() {
+    use std::{cell::RefCell, fmt, rc::Rc};
+
+    #[derive(Debug, Default, Clone)]
+    struct Output(Rc<RefCell<String>>);
+
+    impl fmt::Write for Output {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.borrow_mut().push_str(s);
+            Ok(())
+        }
+    }
+
+    let output = Output::default();
+    let written = output.clone();
+
+    let rows = (0..4).map(move |i| {
+        if i == 3 {
+            assert_eq!(
+                *written.0.borrow(),
+                "+----+------+\n| id | name |\n+----+------+\n| 1  | x    |\n+----+------+\n| 2  | x    |"
+            );
+        }
+
+        match i {
+            0 => vec![String::from("id"), String::from("name")],
+            i => vec![i.to_string(), String::from("x")],
+        }
+    });
+
+    IterTable::new(rows)
+        .sniff(1)
+        .grow()
+        .fmt(output.clone())
+        .unwrap();
+
+    assert_eq!(
+        *output.0.borrow(),
+        "+----+------+\n| id | name |\n+----+------+\n| 1  | x    |\n+----+------+\n| 2  | x    |\n+----+------+\n| 3  | x    |\n+----+------+"
+    );
+}
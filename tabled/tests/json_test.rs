@@ -0,0 +1,185 @@
+#![cfg(feature = "serde_json")]
+
+use std::iter::FromIterator;
+
+use serde_json::{json, Value};
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Columns, Rows, Segment},
+        Alignment, Modify, Padding, Span, Width,
+    },
+    Table,
+};
+
+#[test]
+fn to_json_empty() {
+    let table = Builder::default().build();
+
+    assert_eq!(table.to_json(), json!([]));
+}
+
+#[test]
+fn to_json_records() {
+    let table = Table::new([("Debian", 11), ("Arch", 2023)]);
+
+    assert_eq!(
+        table.to_json(),
+        json!([
+            { "&str": "Debian", "i32": "11" },
+            { "&str": "Arch", "i32": "2023" },
+        ]),
+    );
+}
+
+#[test]
+fn to_json_header_only() {
+    let table = Builder::default().set_header(["name", "version"]).build();
+
+    assert_eq!(table.to_json(), json!([]));
+}
+
+#[test]
+fn to_json_without_header() {
+    let table = Builder::from_iter([["", "1"], ["Debian", "11"]]).build();
+
+    assert_eq!(table.to_json(), json!([["", "1"], ["Debian", "11"]]));
+
+    let table = Builder::from_iter([["a", "a"], ["Debian", "11"]]).build();
+
+    assert_eq!(table.to_json(), json!([["a", "a"], ["Debian", "11"]]));
+}
+
+#[test]
+fn to_json_layout_empty() {
+    let table = Builder::default().build();
+
+    assert_eq!(
+        table.to_json_layout(),
+        json!({
+            "header": [],
+            "rows": [],
+            "spans": [],
+            "alignment": { "horizontal": [], "vertical": [] },
+            "widths": [],
+            "heights": [],
+        }),
+    );
+}
+
+#[test]
+fn to_json_layout_records() {
+    let table = Table::new([("Debian", 11), ("Arch", 2023)]);
+
+    let value = table.to_json_layout();
+
+    assert_eq!(value["header"], json!(["&str", "i32"]));
+    assert_eq!(value["rows"], json!([["Debian", "11"], ["Arch", "2023"]]));
+    assert_eq!(value["widths"], json!([8, 6]));
+    assert_eq!(value["heights"], json!([1, 1, 1]));
+}
+
+#[test]
+fn to_json_layout_multiline() {
+    let table = Table::new([["a\nb\nc", "d"]]);
+
+    let value = table.to_json_layout();
+
+    assert_eq!(value["rows"], json!([["a\nb\nc", "d"]]));
+    assert_eq!(value["heights"], json!([1, 3]));
+}
+
+#[test]
+fn to_json_layout_alignment() {
+    let mut table = Table::new([["1", "2"], ["3", "4"]]);
+    table
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .with(Modify::new(Rows::first()).with(Alignment::center()))
+        .with(Modify::new(Segment::all()).with(Alignment::bottom()));
+
+    let value = table.to_json_layout();
+
+    assert_eq!(
+        value["alignment"],
+        json!({
+            "horizontal": [
+                ["center", "center"],
+                ["left", "right"],
+                ["left", "right"],
+            ],
+            "vertical": [
+                ["bottom", "bottom"],
+                ["bottom", "bottom"],
+                ["bottom", "bottom"],
+            ],
+        }),
+    );
+}
+
+#[test]
+fn to_json_layout_spans() {
+    let mut table = Table::new([["1", "2", "3"], ["4", "5", "6"]]);
+    table
+        .with(Modify::new((0, 0)).with(Span::column(2)))
+        .with(Modify::new((1, 2)).with(Span::row(2)));
+
+    let value = table.to_json_layout();
+
+    assert_eq!(
+        value["spans"],
+        json!([
+            { "row": 0, "column": 0, "rows": 1, "columns": 2 },
+            { "row": 1, "column": 2, "rows": 2, "columns": 1 },
+        ]),
+    );
+}
+
+#[test]
+fn to_json_layout_dimension_matches_output() {
+    let mut table = Table::new([["Hello World", "!"]]);
+    table
+        .with(Padding::new(2, 2, 0, 1))
+        .with(Width::wrap(10).keep_words());
+
+    let value = table.to_json_layout();
+
+    let output = table.to_string();
+    let line = output.lines().next().unwrap();
+    let widths = value["widths"].as_array().unwrap();
+    let total = widths.iter().map(|w| w.as_u64().unwrap()).sum::<u64>();
+
+    assert_eq!(line.chars().count() as u64, total + widths.len() as u64 + 1);
+    assert_eq!(
+        output.lines().count() as u64,
+        value["heights"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|h| h.as_u64().unwrap())
+            .sum::<u64>()
+            + 3
+    );
+}
+
+#[test]
+fn to_json_layout_roundtrip() {
+    let table = Table::new([["Debian", "11"], ["Arch", "2023"]]);
+
+    let value = table.to_json_layout();
+
+    let mut builder = Builder::default().set_header(strings(&value["header"]));
+    for row in value["rows"].as_array().unwrap() {
+        builder.push_record(strings(row));
+    }
+
+    assert_eq!(builder.build().to_string(), table.to_string());
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v.as_str().unwrap().to_owned())
+        .collect()
+}
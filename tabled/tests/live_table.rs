@@ -0,0 +1,95 @@
+#![cfg(feature = "std")]
+
+use tabled::{builder::Builder, tables::live::LiveTable, Table};
+
+fn update(live: &mut LiveTable<Vec<u8>>, table: &Table) -> String {
+    live.get_mut().clear();
+    live.update(table).unwrap();
+    String::from_utf8(live.get_ref().clone()).unwrap()
+}
+
+fn table(rows: &[[&str; 2]]) -> Table {
+    let mut builder = Builder::default();
+    for row in rows {
+        builder.push_record(row.iter().copied());
+    }
+
+    builder.build()
+}
+
+#[test]
+fn live_table_first_update_prints_table() {
+    let mut live = LiveTable::new(Vec::new());
+    let t = table(&[["a", "1"], ["b", "2"]]);
+
+    assert_eq!(update(&mut live, &t), t.to_string());
+}
+
+#[test]
+fn live_table_no_changes() {
+    let mut live = LiveTable::new(Vec::new());
+    let _ = update(&mut live, &table(&[["a", "1"], ["b", "2"]]));
+
+    assert_eq!(update(&mut live, &table(&[["a", "1"], ["b", "2"]])), "");
+}
+
+#[test]
+fn live_table_last_row_changed() {
+    let mut live = LiveTable::new(Vec::new());
+    let _ = update(&mut live, &table(&[["a", "1"], ["b", "2"]]));
+
+    assert_eq!(
+        update(&mut live, &table(&[["a", "1"], ["b", "3"]])),
+        "\u{1b}[1A\r| b | 3 |\u{1b}[K\u{1b}[1B\r\u{1b}[9C"
+    );
+}
+
+#[test]
+fn live_table_width_changed() {
+    let mut live = LiveTable::new(Vec::new());
+    let _ = update(&mut live, &table(&[["a", "1"], ["b", "2"]]));
+
+    assert_eq!(
+        update(&mut live, &table(&[["a", "1"], ["b", "22"]])),
+        concat!(
+            "\u{1b}[4A\r+---+----+\u{1b}[K",
+            "\u{1b}[1B\r| a | 1  |\u{1b}[K",
+            "\u{1b}[1B\r+---+----+\u{1b}[K",
+            "\u{1b}[1B\r| b | 22 |\u{1b}[K",
+            "\u{1b}[1B\r+---+----+\u{1b}[K",
+        )
+    );
+}
+
+#[test]
+fn live_table_grows() {
+    let mut live = LiveTable::new(Vec::new());
+    let _ = update(&mut live, &table(&[["a", "1"]]));
+
+    assert_eq!(
+        update(&mut live, &table(&[["a", "1"], ["b", "2"]])),
+        "\r\n| b | 2 |\r\n+---+---+"
+    );
+}
+
+#[test]
+fn live_table_shrinks() {
+    let mut live = LiveTable::new(Vec::new());
+    let _ = update(&mut live, &table(&[["a", "1"], ["b", "2"]]));
+
+    assert_eq!(
+        update(&mut live, &table(&[["a", "1"]])),
+        "\u{1b}[2A\r+---+---+\u{1b}[J"
+    );
+}
+
+#[test]
+fn live_table_reset() {
+    let mut live = LiveTable::new(Vec::new());
+    let t = table(&[["a", "1"]]);
+    let _ = update(&mut live, &t);
+
+    live.reset();
+
+    assert_eq!(update(&mut live, &t), t.to_string());
+}
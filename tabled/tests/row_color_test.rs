@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{
+    object::{Cell, Rows},
+    Color, Modify, RowColor, Span, Style,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    row_color_rows,
+    create_table::<2, 2>().with(Style::psql()).with(RowColor::new(Rows::single(1), Color::BG_BLUE)),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    "\u{1b}[44m 0 \u{1b}[49m\u{1b}[44m|\u{1b}[49m\u{1b}[44m   0-0    \u{1b}[49m\u{1b}[44m|\u{1b}[49m\u{1b}[44m   0-1    \u{1b}[49m"
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    row_color_cell,
+    create_table::<2, 2>().with(Style::psql()).with(RowColor::new(Cell::new(2, 1), Color::BG_BLUE)),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    "\u{1b}[44m 1 \u{1b}[49m\u{1b}[44m|\u{1b}[49m\u{1b}[44m   1-0    \u{1b}[49m\u{1b}[44m|\u{1b}[49m\u{1b}[44m   1-1    \u{1b}[49m"
+);
+
+test_table!(
+    row_color_row_span,
+    create_table::<2, 2>()
+        .with(Style::modern())
+        .with(Modify::new((1, 0)).with(Span::row(2)))
+        .with(RowColor::new(Rows::single(1), Color::BG_BLUE)),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "\u{1b}[44m│\u{1b}[49m\u{1b}[44m 0 \u{1b}[49m\u{1b}[44m│\u{1b}[49m\u{1b}[44m   0-0    \u{1b}[49m\u{1b}[44m│\u{1b}[49m\u{1b}[44m   0-1    \u{1b}[49m\u{1b}[44m│\u{1b}[49m"
+    "├\u{1b}[44m   \u{1b}[49m┼──────────┼──────────┤"
+    "│\u{1b}[44m   \u{1b}[49m│   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
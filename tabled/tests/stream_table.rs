@@ -0,0 +1,137 @@
+#![cfg(feature = "async")]
+
+mod util;
+
+use futures_executor::block_on;
+use futures_util::{io::Cursor, stream};
+use tabled::{
+    settings::{Margin, Style},
+    tables::{iter::IterTable, stream::StreamTable},
+};
+use util::{create_matrix, test_table};
+
+fn build<S>(table: StreamTable<S>) -> String
+where
+    S: futures_util::Stream + Unpin,
+    S::Item: IntoIterator,
+    <S::Item as IntoIterator>::Item: AsRef<str>,
+{
+    let mut buf = Cursor::new(Vec::new());
+    block_on(table.build(&mut buf)).unwrap();
+    String::from_utf8(buf.into_inner()).unwrap()
+}
+
+test_table!(
+    stream_table,
+    build(StreamTable::new(stream::iter(create_matrix::<3, 3>()))),
+    "+-----+-----+-----+"
+    "| 0-0 | 0-1 | 0-2 |"
+    "+-----+-----+-----+"
+    "| 1-0 | 1-1 | 1-2 |"
+    "+-----+-----+-----+"
+    "| 2-0 | 2-1 | 2-2 |"
+    "+-----+-----+-----+"
+);
+
+test_table!(
+    stream_table_empty,
+    build(StreamTable::new(stream::iter(Vec::<Vec<String>>::new()))),
+    ""
+);
+
+test_table!(
+    stream_table_cols_less,
+    build(StreamTable::new(stream::iter(create_matrix::<3, 3>())).columns(2)),
+    "+-----+-----+"
+    "| 0-0 | 0-1 |"
+    "+-----+-----+"
+    "| 1-0 | 1-1 |"
+    "+-----+-----+"
+    "| 2-0 | 2-1 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    stream_table_rows,
+    build(StreamTable::new(stream::iter(create_matrix::<3, 3>())).rows(1)),
+    "+-----+-----+-----+"
+    "| 0-0 | 0-1 | 0-2 |"
+    "+-----+-----+-----+"
+);
+
+test_table!(
+    stream_table_width,
+    build(StreamTable::new(stream::iter(create_matrix::<3, 3>())).width(2).columns(3)),
+    "+----+----+----+"
+    "| 0- | 0- | 0- |"
+    "+----+----+----+"
+    "| 1- | 1- | 1- |"
+    "+----+----+----+"
+    "| 2- | 2- | 2- |"
+    "+----+----+----+"
+);
+
+test_table!(
+    stream_table_sniff_cut,
+    build(
+        StreamTable::new(stream::iter(vec![
+            vec!["12", "12", "22", "32"],
+            vec!["0", "0", "0", "0"],
+            vec!["023", "123", "223", "323"],
+        ]))
+        .sniff(2)
+    ),
+    "+----+----+----+----+"
+    "| 12 | 12 | 22 | 32 |"
+    "+----+----+----+----+"
+    "| 0  | 0  | 0  | 0  |"
+    "+----+----+----+----+"
+    "| 02 | 12 | 22 | 32 |"
+    "+----+----+----+----+"
+);
+
+test_table!(
+    stream_table_multiline_height,
+    build(
+        StreamTable::new(stream::iter(vec![
+            vec!["0", "1"],
+            vec!["0\n1\n2\n3", "0\n1"],
+        ]))
+        .height(3)
+    ),
+    "+---+---+"
+    "| 0 | 1 |"
+    "|   |   |"
+    "|   |   |"
+    "+---+---+"
+    "| 0 | 0 |"
+    "| 1 | 1 |"
+    "| 2 |   |"
+    "+---+---+"
+);
+
+#[test]
+fn stream_table_matches_iter_table() {
+    macro_rules! check {
+        ($style:expr) => {{
+            let data = create_matrix::<4, 3>();
+            let expected = IterTable::new(&data).with($style).to_string();
+            let got = build(StreamTable::new(stream::iter(&data)).with($style));
+            assert_eq!(got, expected);
+        }};
+    }
+
+    check!(Style::ascii());
+    check!(Style::modern());
+    check!(Style::sharp());
+    check!(Style::rounded());
+    check!(Style::psql());
+    check!(Style::markdown());
+    check!(Style::re_structured_text());
+    check!(Style::dots());
+    check!(Style::blank());
+    check!(Style::empty());
+    check!(Style::extended());
+    check!(Style::ascii_rounded());
+    check!(Margin::new(1, 2, 1, 2));
+}
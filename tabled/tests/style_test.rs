@@ -10,7 +10,7 @@ use tabled::{
             Border, BorderChar, BorderSpanCorrection, BorderText, HorizontalLine, Line, Offset,
             RawStyle, Style, VerticalLine,
         },
-        Format, Highlight, Modify, Padding, Span,
+        Color, ColorDepth, Format, Highlight, Modify, Padding, Span, Width,
     },
     Table,
 };
@@ -19,8 +19,6 @@ use crate::util::{create_table, init_table, static_table, test_table};
 
 #[cfg(feature = "color")]
 use std::convert::TryFrom;
-#[cfg(feature = "color")]
-use tabled::settings::Color;
 
 mod util;
 
@@ -2601,3 +2599,57 @@ test_table!(
     "|     ! |xxxx"
     "+-------+xxxx"
 );
+
+test_table!(
+    color_depth_ansi256_test,
+    Table::new([["a", "b"]])
+        .with(Style::modern())
+        .with(Color::rgb_fg(0, 135, 175))
+        .with(Modify::new(Rows::first()).with(Color::palette_bg(208)))
+        .with(ColorDepth::Ansi256),
+    "┌───┬───┐"
+    "│ \u{1b}[48;5;208m0\u{1b}[49m │ \u{1b}[48;5;208m1\u{1b}[49m │"
+    "├───┼───┤"
+    "│ \u{1b}[38;5;31ma\u{1b}[39m │ \u{1b}[38;5;31mb\u{1b}[39m │"
+    "└───┴───┘"
+);
+
+test_table!(
+    color_depth_none_test,
+    Table::new([["a", "b"]])
+        .with(Style::ascii())
+        .with(Modify::new(Rows::first()).with(Color::rgb_bg(0, 0, 0)))
+        .with(Modify::new(Rows::last()).with(Color::new(String::from("\u{1b}[1;31m"), String::from("\u{1b}[0m"))))
+        .with(ColorDepth::None),
+    "+---+---+"
+    "| 0 | 1 |"
+    "+---+---+"
+    "| \u{1b}[1ma\u{1b}[0m | \u{1b}[1mb\u{1b}[0m |"
+    "+---+---+"
+);
+
+test_table!(
+    color_attributes_combined_test,
+    Table::new([["a", "b"]])
+        .with(Style::ascii())
+        .with(Modify::new(Rows::last()).with(Color::BOLD | Color::UNDERLINE | Color::FG_RED)),
+    "+---+---+"
+    "| 0 | 1 |"
+    "+---+---+"
+    "| \u{1b}[1m\u{1b}[4m\u{1b}[31ma\u{1b}[39m\u{1b}[24m\u{1b}[22m | \u{1b}[1m\u{1b}[4m\u{1b}[31mb\u{1b}[39m\u{1b}[24m\u{1b}[22m |"
+    "+---+---+"
+);
+
+test_table!(
+    color_attributes_wrapped_test,
+    Table::new([["Hello World"]])
+        .with(Style::ascii())
+        .with(Modify::new(Rows::last()).with(Color::ITALIC | Color::BG_BLUE))
+        .with(Width::wrap(9).keep_words()),
+    "+-------+"
+    "| 0     |"
+    "+-------+"
+    "| \u{1b}[3m\u{1b}[44mHello\u{1b}[49m\u{1b}[23m |"
+    "| \u{1b}[3m\u{1b}[44mWorld\u{1b}[49m\u{1b}[23m |"
+    "+-------+"
+);
@@ -6,9 +6,10 @@ use tabled::{
         formatting::{TabSize, TrimStrategy},
         object::{Columns, Object, Rows, Segment},
         peaker::{PriorityMax, PriorityMin},
-        width::{Justify, MinWidth, SuffixLimit, Width},
+        width::{Justify, MinWidth, SuffixLimit, TruncatePosition, Width},
         Alignment, Margin, Modify, Padding, Panel, Settings, Span, Style,
     },
+    Table,
 };
 
 use crate::util::{create_table, init_table, is_lines_equal, new_table, static_table};
@@ -506,6 +507,7 @@ fn dont_change_content_if_width_is_less_then_max_width() {
     );
 }
 
+#[cfg(not(feature = "grapheme"))]
 #[test]
 fn max_width_with_emoji() {
     let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
@@ -2298,7 +2300,7 @@ fn min_width_is_used_after_margin() {
 #[test]
 fn wrap_keeping_words_0() {
     let data = vec![["Hello world"]];
-    let table = tabled::Table::new(data)
+    let table = Table::new(data)
         .with(Width::wrap(8).keep_words())
         .to_string();
 
@@ -2636,8 +2638,7 @@ mod derived {
              | Debia |       |\n\
              | n 2   |       |\n\
              | links |       |\n\
-             |  in a |       |\n\
-             |       |       |\n\
+             | in a  |       |\n\
              | strin |       |\n\
              | g     |       |\n\
              | Debia |       |\n\
@@ -2646,3 +2647,366 @@ mod derived {
         );
     }
 }
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn wrap_grapheme_clusters_test() {
+    let table = Table::new(["👨‍👩‍👧👨‍👩‍👧🇺🇦", "e\u{301}e\u{301}e\u{301}"])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(2)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| 👨‍👩‍👧   |"
+            "| 👨‍👩‍👧   |"
+            "| 🇺🇦   |"
+            "| e\u{301}e\u{301}   |"
+            "| e\u{301}    |"
+        )
+    );
+}
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn truncate_grapheme_clusters_test() {
+    let table = Table::new(["🇺🇦🇩🇪", "👍🏽ok"])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::truncate(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| 🇺🇦�  |"
+            "| 👍🏽o  |"
+        )
+    );
+}
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn max_width_with_emoji_grapheme() {
+    let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
+
+    let table = new_table(data)
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::truncate(6).suffix("...")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  &str  |"
+            "|--------|"
+            "|   🤠   |"
+            "| 😳�... |"
+            "| 🚴🏻‍♀️�... |"
+        )
+    );
+}
+
+#[test]
+fn wrap_break_on_punctuation_test() {
+    let table = Table::new(["https://github.com/zhiburt/tabled", "snake_case_name"])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(12).break_on_punctuation()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str        |"
+            "|-------------|"
+            "| https://    |"
+            "| github.com/ |"
+            "| zhiburt/    |"
+            "| tabled      |"
+            "| snake_case_ |"
+            "| name        |"
+        )
+    );
+}
+
+#[test]
+fn wrap_hyphenate_test() {
+    let table = Table::new(["a supercalifragilistic word"])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(8).hyphenate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str     |"
+            "|----------|"
+            "| a        |"
+            "| superca- |"
+            "| lifragi- |"
+            "| listic   |"
+            "| word     |"
+        )
+    );
+}
+
+#[test]
+fn wrap_balance_test() {
+    let text = "aaa bb cc ddddd";
+
+    // greedy wrapping leaves a big gap on the second line.
+
+    let greedy = Table::new([text])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).keep_words()))
+        .to_string();
+
+    assert_eq!(
+        greedy,
+        static_table!(
+            "| &str   |"
+            "|--------|"
+            "| aaa bb |"
+            "| cc     |"
+            "| ddddd  |"
+        )
+    );
+
+    let balanced = Table::new([text])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).balance()))
+        .to_string();
+
+    assert_eq!(
+        balanced,
+        static_table!(
+            "| &str  |"
+            "|-------|"
+            "| aaa   |"
+            "| bb cc |"
+            "| ddddd |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn wrap_balance_color_test() {
+    use owo_colors::OwoColorize;
+
+    let text = "aaa bb cc ddddd".red().to_string();
+
+    let greedy = Table::new([&text])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).keep_words()))
+        .to_string();
+
+    assert_eq!(
+        greedy,
+        static_table!(
+            "| String |"
+            "|--------|"
+            "| \u{1b}[31maaa bb\u{1b}[39m |"
+            "| \u{1b}[31mcc \u{1b}[39m    |"
+            "| \u{1b}[31mddddd\u{1b}[39m  |"
+        )
+    );
+
+    let balanced = Table::new([&text])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).balance()))
+        .to_string();
+
+    assert_eq!(
+        balanced,
+        static_table!(
+            "| String |"
+            "|--------|"
+            "| \u{1b}[31maaa\u{1b}[39m    |"
+            "| \u{1b}[31mbb cc\u{1b}[39m  |"
+            "| \u{1b}[31mddddd\u{1b}[39m  |"
+        )
+    );
+}
+
+#[test]
+fn wrap_balance_total_width_test() {
+    let table = Table::new(["one two three four five six"])
+        .with(Style::markdown())
+        .with(Width::wrap(16).balance())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str         |"
+            "|--------------|"
+            "| one two      |"
+            "| three four   |"
+            "| five six     |"
+        )
+    );
+}
+
+#[test]
+fn truncate_position_start_test() {
+    let table = Table::new(["/home/user/src/main.rs", "id"])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..)).with(
+                Width::truncate(10)
+                    .suffix("...")
+                    .position(TruncatePosition::Start),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str       |"
+            "|------------|"
+            "| ...main.rs |"
+            "| id         |"
+        )
+    );
+}
+
+#[test]
+fn truncate_position_middle_test() {
+    let table = Table::new(["0123456789"])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..)).with(
+                Width::truncate(5)
+                    .suffix("..")
+                    .position(TruncatePosition::Middle),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str  |"
+            "|-------|"
+            "| 01..9 |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn truncate_position_suffix_try_color_test() {
+    use owo_colors::OwoColorize;
+
+    let text = "0123456789".red().to_string();
+
+    let table = Table::new([text.clone()])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..)).with(
+                Width::truncate(5)
+                    .suffix("..")
+                    .suffix_try_color(true)
+                    .position(TruncatePosition::Start),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| String |"
+            "|--------|"
+            "| \u{1b}[31m..\u{1b}[39m\u{1b}[31m789\u{1b}[39m  |"
+        )
+    );
+
+    let table = Table::new([text])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..)).with(
+                Width::truncate(5)
+                    .suffix("..")
+                    .suffix_try_color(true)
+                    .position(TruncatePosition::Middle),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| String |"
+            "|--------|"
+            "| \u{1b}[31m01\u{1b}[39m\u{1b}[31m..\u{1b}[39m\u{1b}[31m9\u{1b}[39m  |"
+        )
+    );
+}
+
+#[test]
+fn truncate_position_multiline_test() {
+    let table = Table::new(["first line\nok\nthird line"])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..)).with(Width::truncate(6).position(TruncatePosition::Start)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str   |"
+            "|--------|"
+            "| t line |"
+            "| ok     |"
+            "| d line |"
+        )
+    );
+}
+
+#[test]
+fn truncate_position_emoji_test() {
+    let table = Table::new(["😳😳😳"])
+        .with(Style::markdown())
+        .with(
+            Modify::new(Rows::new(1..)).with(Width::truncate(3).position(TruncatePosition::Start)),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| �😳  |"
+        )
+    );
+}
+
+#[test]
+fn truncate_position_priority_test() {
+    let table = Table::new([("/var/log/syslog", "2023-01-01T00:00:00")])
+        .with(Style::markdown())
+        .with(
+            Width::truncate(25)
+                .suffix("~")
+                .position(TruncatePosition::Middle)
+                .priority::<PriorityMax>(),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str      | &str      |"
+            "|-----------|-----------|"
+            "| /var~slog | 2023~0:00 |"
+        )
+    );
+}
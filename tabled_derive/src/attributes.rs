@@ -12,6 +12,7 @@ pub struct Attributes {
     pub display_with: Option<String>,
     pub display_with_args: Option<Vec<FuncArg>>,
     pub order: Option<usize>,
+    pub link: Option<String>,
 }
 
 impl Attributes {
@@ -64,6 +65,7 @@ impl Attributes {
                 }
             }
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            parse::TabledAttrKind::Link(value) => self.link = Some(value.value()),
         }
 
         Ok(())
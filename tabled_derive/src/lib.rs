@@ -163,6 +163,7 @@ fn info_from_fields(
     header_prefix: &str,
 ) -> Result<Impl, Error> {
     let count_fields = fields.len();
+    let all_fields = fields;
 
     let fields = fields
         .into_iter()
@@ -199,8 +200,17 @@ fn info_from_fields(
         let header = field_headers(field, i, &attributes, header_prefix);
         headers.push(header);
 
-        let field_name = field_name(i, field);
-        let value = get_field_fields(&field_name, &attributes);
+        let value = get_field_fields(&field_name(i, field), &attributes);
+        let value = match &attributes.link {
+            Some(link) => {
+                let (link_index, link_field) = find_field(all_fields, link)
+                    .ok_or_else(|| Error::message(format!("A link field '{link}' is not found")))?;
+
+                wrap_link(&value, &field_name(link_index, link_field))
+            }
+            None => value,
+        };
+
         values.push(value);
     }
 
@@ -451,6 +461,34 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
+fn find_field<'a>(fields: &'a Fields, name: &str) -> Option<(usize, &'a Field)> {
+    fields
+        .iter()
+        .enumerate()
+        .find(|(i, field)| match &field.ident {
+            Some(ident) => ident == name,
+            None => i.to_string() == name,
+        })
+}
+
+/// Wraps each line of values into an OSC 8 hyperlink.
+fn wrap_link(values: &TokenStream, url: &TokenStream) -> TokenStream {
+    quote!({
+        let __link_url = format!("{}", #url);
+        #values
+            .into_iter()
+            .map(|value: ::std::borrow::Cow<'_, str>| {
+                if __link_url.is_empty() {
+                    return value;
+                }
+
+                let value = ::tabled::settings::hyperlink::make_link(&__link_url, &value);
+                ::std::borrow::Cow::Owned(value)
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
 fn use_function(args: &TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
@@ -33,6 +33,7 @@ pub enum TabledAttrKind {
     RenameAll(LitStr),
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
+    Link(LitStr),
 }
 
 impl Parse for TabledAttr {
@@ -51,6 +52,7 @@ impl Parse for TabledAttr {
                 match name_str.as_str() {
                     "rename" => return Ok(Self::new(name, Rename(lit))),
                     "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
+                    "link" => return Ok(Self::new(name, Link(lit))),
                     "display_with" => {
                         return Ok(Self::new(name, DisplayWith(lit, None, Punctuated::new())))
                     }